@@ -0,0 +1,11 @@
+// Sets `cfg(nostd)` when the "no-std" feature is enabled without "std".
+// "std" takes precedence, so builds with --all-features use std.
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(nostd)");
+    println!("cargo:rerun-if-changed=build.rs");
+    if std::env::var_os("CARGO_FEATURE_NO_STD").is_some()
+        && std::env::var_os("CARGO_FEATURE_STD").is_none()
+    {
+        println!("cargo:rustc-cfg=nostd");
+    }
+}
@@ -0,0 +1,91 @@
+// channel-based log queue (feature "async-queue")
+use crate::{LogEntry, LogQueue};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Log queue backed by a bounded tokio mpsc channel, so producers never wait for a lock.
+/// Use [AsyncLogQueue::new] to create a sender and receiver.
+///
+/// ```
+/// # async fn run() {
+/// use service_logging::{log, AsyncLogQueue, Severity};
+/// let (sender, mut receiver) = AsyncLogQueue::new(100);
+/// log!(sender, Severity::Info, text: "hello");
+/// assert_eq!(receiver.take_all().await.len(), 1);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncLogQueue;
+
+/// Producer half of an [AsyncLogQueue]. Clones send to the same receiver
+#[derive(Clone, Debug)]
+pub struct AsyncLogQueueSender {
+    tx: mpsc::Sender<LogEntry>,
+    dropped: Arc<AtomicU64>,
+}
+
+/// Consumer half of an [AsyncLogQueue]
+#[derive(Debug)]
+pub struct AsyncLogQueueReceiver {
+    rx: mpsc::Receiver<LogEntry>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl AsyncLogQueue {
+    /// Creates a queue that holds up to `capacity` entries. Panics if `capacity` is 0
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(capacity: usize) -> (AsyncLogQueueSender, AsyncLogQueueReceiver) {
+        let (tx, rx) = mpsc::channel(capacity);
+        let dropped = Arc::new(AtomicU64::new(0));
+        (
+            AsyncLogQueueSender {
+                tx,
+                dropped: dropped.clone(),
+            },
+            AsyncLogQueueReceiver { rx, dropped },
+        )
+    }
+}
+
+impl AsyncLogQueueSender {
+    /// Adds an entry to the queue without waiting. If the queue is full
+    /// or the receiver was dropped, the entry is dropped and counted
+    pub fn log(&self, e: LogEntry) {
+        if self.tx.try_send(e).is_err() {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the number of entries dropped because the queue was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+impl AsyncLogQueueReceiver {
+    /// Returns all entries currently in the queue, without waiting for more
+    pub async fn take_all(&mut self) -> Vec<LogEntry> {
+        let mut entries = Vec::new();
+        while let Ok(e) = self.rx.try_recv() {
+            entries.push(e);
+        }
+        entries
+    }
+
+    /// Waits for the next entry. Returns None when all senders have been dropped
+    /// and the queue is empty
+    pub async fn recv(&mut self) -> Option<LogEntry> {
+        self.rx.recv().await
+    }
+
+    /// Returns all entries currently in the queue as a LogQueue
+    pub async fn take_queue(&mut self) -> LogQueue {
+        LogQueue::from(self.take_all().await)
+    }
+
+    /// Returns the number of entries dropped because the queue was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,111 @@
+// Logger for Azure Monitor / Application Insights (feature "azure"), using the
+// Application Insights track api, which is authenticated by the instrumentation key in each envelope
+// https://learn.microsoft.com/en-us/azure/azure-monitor/app/api-custom-events-metrics
+use crate::logging::{check_status, http_client};
+use crate::time::format_rfc3339;
+use crate::{LogEntry, Logger, ServiceLoggingError, Severity};
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+
+/// Configuration parameters for Azure Monitor
+#[derive(Clone, Debug, Default)]
+pub struct AzureMonitorConfig {
+    /// Application Insights instrumentation key
+    pub instrumentation_key: String,
+    /// Track api url, for the ingestion endpoint in the resource's connection string.
+    /// If empty, the global endpoint `https://dc.services.visualstudio.com/v2/track` is used
+    pub endpoint: String,
+}
+
+// global Application Insights ingestion endpoint
+const DEFAULT_ENDPOINT: &str = "https://dc.services.visualstudio.com/v2/track";
+
+/// Implementation of Logger for [Azure Monitor](https://azure.microsoft.com/en-us/products/monitor/).
+/// Entries are sent to the Application Insights track api as `MessageTelemetry` envelopes,
+/// with the subsystem in `properties`.
+#[derive(Debug)]
+pub struct AzureMonitorLogger {
+    config: AzureMonitorConfig,
+    client: reqwest::Client,
+}
+
+impl Severity {
+    // Application Insights SeverityLevel: Verbose=0, Information=1, Warning=2, Error=3, Critical=4
+    fn azure_severity_level(&self) -> u8 {
+        match self {
+            Severity::Debug | Severity::Verbose => 0,
+            Severity::Info => 1,
+            Severity::Warning => 2,
+            Severity::Error => 3,
+            Severity::Critical => 4,
+        }
+    }
+}
+
+impl AzureMonitorLogger {
+    /// Initialize logger with configuration
+    pub fn init(
+        mut config: AzureMonitorConfig,
+    ) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        if config.endpoint.is_empty() {
+            config.endpoint = DEFAULT_ENDPOINT.to_string();
+        }
+        let client = http_client(reqwest::header::HeaderMap::new())?;
+        Ok(Box::new(Self { config, client }))
+    }
+
+    // MessageTelemetry envelope for the entry. Other fields are included in `properties`
+    fn envelope(&self, sub: &str, entry: &LogEntry) -> Value {
+        let mut properties = Map::new();
+        properties.insert("subsystem".into(), sub.into());
+        for (key, val) in [
+            ("category", &entry.category),
+            ("className", &entry.class_name),
+            ("methodName", &entry.method_name),
+            ("threadId", &entry.thread_id),
+            ("traceId", &entry.trace_id),
+            ("spanId", &entry.span_id),
+        ] {
+            if let Some(val) = val {
+                properties.insert(key.into(), val.clone().into());
+            }
+        }
+        for (key, val) in entry.custom_fields.iter() {
+            properties.insert(key.clone(), val.clone().into());
+        }
+        json!({
+            "name": "Microsoft.ApplicationInsights.Message",
+            "time": format_rfc3339(entry.timestamp),
+            "iKey": self.config.instrumentation_key,
+            "data": {
+                "baseType": "MessageData",
+                "baseData": {
+                    "ver": 2,
+                    "message": entry.text,
+                    "severityLevel": entry.severity.azure_severity_level(),
+                    "properties": properties,
+                }
+            }
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for AzureMonitorLogger {
+    /// Send logs to Azure Monitor.
+    /// May return error if there was a problem sending.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let envelopes: Vec<Value> = entries.iter().map(|e| self.envelope(sub, e)).collect();
+        let resp = self
+            .client
+            .post(&self.config.endpoint)
+            .json(&envelopes)
+            .send()
+            .await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+// Logger for the Fluentd / Fluent Bit http input plugin (feature "fluentd")
+// https://docs.fluentd.org/input/http
+use crate::logging::{check_status, http_client};
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use serde_json::Value;
+
+/// Configuration parameters for Fluentd
+#[derive(Clone, Debug, Default)]
+pub struct FluentdConfig {
+    /// Base url of the http input, e.g., `http://localhost:9880`
+    pub endpoint: String,
+    /// Fluentd tag for the records, used as the url path
+    pub tag: String,
+}
+
+/// Implementation of Logger for the Fluentd and Fluent Bit http input.
+/// With the `msgpack` feature, entries are sent as a msgpack array of `[time, record]` tuples;
+/// otherwise as a json array of records, each with a `time` field (unix seconds).
+#[derive(Debug)]
+pub struct FluentdLogger {
+    url: String,
+    client: reqwest::Client,
+}
+
+// Fluentd record for the entry: the json fields of the entry, plus subsystem
+fn record(sub: &str, entry: &LogEntry) -> Result<Value, serde_json::Error> {
+    let mut record = serde_json::to_value(entry)?;
+    record["subsystem"] = sub.into();
+    Ok(record)
+}
+
+impl FluentdLogger {
+    /// Initialize logger with configuration
+    pub fn init(config: FluentdConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let url = format!(
+            "{}/{}",
+            config.endpoint.trim_end_matches('/'),
+            config.tag.trim_start_matches('/')
+        );
+        let client = http_client(reqwest::header::HeaderMap::new())?;
+        Ok(Box::new(Self { url, client }))
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for FluentdLogger {
+    /// Send logs to Fluentd.
+    /// May return error if there was a problem sending.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let req = self.client.post(&self.url);
+        #[cfg(feature = "msgpack")]
+        let req = {
+            let tuples = entries
+                .iter()
+                .map(|e| Ok((e.timestamp / 1000, record(sub, e)?)))
+                .collect::<Result<Vec<(u64, Value)>, serde_json::Error>>()?;
+            let body = rmp_serde::to_vec(&tuples)?;
+            req.header(reqwest::header::CONTENT_TYPE, "application/msgpack")
+                .body(body)
+        };
+        #[cfg(not(feature = "msgpack"))]
+        let req = {
+            let records = entries
+                .iter()
+                .map(|e| {
+                    let mut r = record(sub, e)?;
+                    r["time"] = (e.timestamp as f64 / 1000.0).into();
+                    Ok(r)
+                })
+                .collect::<Result<Vec<Value>, serde_json::Error>>()?;
+            req.json(&records)
+        };
+        let resp = req.send().await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+// Logger for Honeycomb (feature "honeycomb"), using the batch events api
+// https://docs.honeycomb.io/api/events/
+use crate::logging::{check_status, http_client};
+use crate::time::format_rfc3339;
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde_json::{json, Value};
+
+/// Configuration parameters for Honeycomb
+#[derive(Clone, Debug, Default)]
+pub struct HoneycombConfig {
+    /// API key, sent in the `X-Honeycomb-Team` header
+    pub api_key: String,
+    /// Dataset name
+    pub dataset: String,
+    /// URL prefix for service invocation, e.g., `https://api.honeycomb.io`
+    pub endpoint: String,
+}
+
+/// Implementation of Logger for [Honeycomb](https://www.honeycomb.io/)
+#[derive(Debug)]
+pub struct HoneycombLogger {
+    url: String,
+    client: reqwest::Client,
+}
+
+// Honeycomb event for the entry. Fields are flattened into `data`, with
+// custom_fields and metrics alongside the standard fields
+fn event(sub: &str, entry: &LogEntry) -> Result<Value, serde_json::Error> {
+    let mut data = match serde_json::to_value(entry)? {
+        Value::Object(map) => map,
+        _ => Default::default(),
+    };
+    data.remove("customFields");
+    data.remove("metrics");
+    for (key, val) in entry.custom_fields.iter() {
+        data.insert(key.clone(), val.clone().into());
+    }
+    for (key, val) in entry.metrics.iter() {
+        data.insert(key.clone(), json!(val));
+    }
+    data.insert("subsystem".into(), sub.into());
+    Ok(json!({
+        "time": format_rfc3339(entry.timestamp),
+        "data": data,
+    }))
+}
+
+impl HoneycombLogger {
+    /// Initialize logger with configuration
+    pub fn init(config: HoneycombConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let mut headers = HeaderMap::new();
+        let mut api_key = HeaderValue::from_str(&config.api_key).map_err(|_| {
+            ServiceLoggingError::ConfigError("Invalid Honeycomb api key".to_string())
+        })?;
+        api_key.set_sensitive(true);
+        headers.insert("X-Honeycomb-Team", api_key);
+        let url = format!(
+            "{}/1/batch/{}",
+            config.endpoint.trim_end_matches('/'),
+            config.dataset
+        );
+        let client = http_client(headers)?;
+        Ok(Box::new(Self { url, client }))
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for HoneycombLogger {
+    /// Send logs to Honeycomb.
+    /// May return error if there was a problem sending.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let events = entries
+            .iter()
+            .map(|e| event(sub, e))
+            .collect::<Result<Vec<Value>, serde_json::Error>>()?;
+        let resp = self.client.post(&self.url).json(&events).send().await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+// Logger for Mezmo, formerly LogDNA (feature "mezmo"), using the ingestion api
+// https://docs.mezmo.com/log-analysis-api/ref#ingest
+use crate::logging::{check_status, http_client};
+use crate::time::current_time_millis;
+use crate::{LogEntry, Logger, ServiceLoggingError, Severity};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde_json::{json, Value};
+
+/// Configuration parameters for Mezmo
+#[derive(Clone, Debug, Default)]
+pub struct MezmoConfig {
+    /// Ingestion key
+    pub ingestion_key: String,
+    /// Host name for all lines
+    pub hostname: String,
+    /// Optional app name. If None, the subsystem is used
+    pub app: Option<String>,
+    /// Optional environment, e.g., "production"
+    pub env: Option<String>,
+    /// Ingestion url, e.g., `https://logs.mezmo.com/logs/ingest`
+    pub endpoint: String,
+}
+
+/// Implementation of Logger for [Mezmo](https://www.mezmo.com/)
+#[derive(Debug)]
+pub struct MezmoLogger {
+    config: MezmoConfig,
+    client: reqwest::Client,
+}
+
+impl Severity {
+    // Mezmo level name
+    fn mezmo_level(&self) -> &'static str {
+        match self {
+            Severity::Debug => "TRACE",
+            Severity::Verbose => "DEBUG",
+            Severity::Info => "INFO",
+            Severity::Warning => "WARN",
+            Severity::Error => "ERROR",
+            Severity::Critical => "FATAL",
+        }
+    }
+}
+
+impl MezmoLogger {
+    /// Initialize logger with configuration
+    pub fn init(config: MezmoConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let mut headers = HeaderMap::new();
+        let mut auth =
+            HeaderValue::from_str(&format!("user {}", config.ingestion_key)).map_err(|_| {
+                ServiceLoggingError::ConfigError("Invalid Mezmo ingestion key".to_string())
+            })?;
+        auth.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth);
+        let client = http_client(headers)?;
+        Ok(Box::new(Self { config, client }))
+    }
+
+    // Mezmo line for the entry. custom_fields are included in `meta`
+    fn line(&self, sub: &str, entry: &LogEntry) -> Value {
+        let mut line = json!({
+            "timestamp": entry.timestamp,
+            "line": entry.text,
+            "level": entry.severity.mezmo_level(),
+            "app": self.config.app.as_deref().unwrap_or(sub),
+        });
+        if let Some(env) = entry.environment.as_ref().or(self.config.env.as_ref()) {
+            line["env"] = env.clone().into();
+        }
+        if !entry.custom_fields.is_empty() {
+            line["meta"] = json!(entry.custom_fields);
+        }
+        line
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for MezmoLogger {
+    /// Send logs to Mezmo. Entry tags are combined into the `tags` query parameter.
+    /// May return error if there was a problem sending.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut tags: Vec<&str> = entries
+            .iter()
+            .flat_map(|e| e.tags.iter().map(String::as_str))
+            .collect();
+        tags.sort_unstable();
+        tags.dedup();
+        let mut query = vec![
+            ("hostname", self.config.hostname.clone()),
+            ("now", current_time_millis().to_string()),
+        ];
+        if !tags.is_empty() {
+            query.push(("tags", tags.join(",")));
+        }
+        let lines: Vec<Value> = entries.iter().map(|e| self.line(sub, e)).collect();
+        let resp = self
+            .client
+            .post(&self.config.endpoint)
+            .query(&query)
+            .json(&json!({ "lines": lines }))
+            .send()
+            .await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+}
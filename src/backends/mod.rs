@@ -0,0 +1,23 @@
+// Loggers for additional logging services. Each is enabled with a feature flag
+#[cfg(feature = "azure")]
+pub(crate) mod azure;
+#[cfg(feature = "otel")]
+pub(crate) mod coralogix_otlp;
+#[cfg(feature = "fluentd")]
+pub(crate) mod fluentd;
+#[cfg(feature = "honeycomb")]
+pub(crate) mod honeycomb;
+#[cfg(feature = "mezmo")]
+pub(crate) mod mezmo;
+#[cfg(feature = "newrelic")]
+pub(crate) mod newrelic;
+#[cfg(feature = "otel")]
+pub(crate) mod otel;
+#[cfg(feature = "papertrail")]
+pub(crate) mod papertrail;
+#[cfg(feature = "sentry")]
+pub(crate) mod sentry;
+#[cfg(feature = "sqlite")]
+pub(crate) mod sqlite;
+#[cfg(feature = "webhook")]
+pub(crate) mod webhook;
@@ -0,0 +1,118 @@
+// Logger for New Relic (feature "newrelic"), using the Log API
+// https://docs.newrelic.com/docs/logs/log-api/introduction-log-api/
+use crate::logging::{check_status_sized, http_client};
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_ENCODING, CONTENT_TYPE};
+use serde_json::{json, Value};
+use std::io::Write;
+
+/// Payloads larger than this are gzip-compressed
+const GZIP_THRESHOLD: usize = 1_000_000;
+/// Maximum payload size accepted by New Relic, after compression
+const MAX_PAYLOAD_SIZE: usize = 1_000_000;
+
+/// Configuration parameters for New Relic
+#[derive(Clone, Debug, Default)]
+pub struct NewRelicConfig {
+    /// License or ingest key, sent in the `Api-Key` header
+    pub api_key: String,
+    /// Log API url, e.g., `https://log-api.newrelic.com/log/v1`
+    pub endpoint: String,
+}
+
+/// Implementation of Logger for the [New Relic](https://newrelic.com/) Log API.
+/// Payloads over 1MB are gzip-compressed. Returns PayloadTooLarge if the
+/// compressed payload is still over 1MB.
+#[derive(Debug)]
+pub struct NewRelicLogger {
+    endpoint: String,
+    client: reqwest::Client,
+}
+
+// New Relic log object for the entry. Fields other than timestamp, text, and severity
+// become top-level attributes, with custom_fields and metrics flattened
+fn log_object(entry: &LogEntry) -> Result<Value, serde_json::Error> {
+    let mut obj = match serde_json::to_value(entry)? {
+        Value::Object(map) => map,
+        _ => Default::default(),
+    };
+    for key in ["timestamp", "text", "severity", "customFields", "metrics"].iter() {
+        obj.remove(*key);
+    }
+    for (key, val) in entry.custom_fields.iter() {
+        obj.insert(key.clone(), val.clone().into());
+    }
+    for (key, val) in entry.metrics.iter() {
+        obj.insert(key.clone(), json!(val));
+    }
+    obj.insert("timestamp".into(), entry.timestamp.into());
+    obj.insert("message".into(), entry.text.clone().into());
+    obj.insert(
+        "level".into(),
+        entry.severity.to_string().to_lowercase().into(),
+    );
+    Ok(Value::Object(obj))
+}
+
+fn gzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+impl NewRelicLogger {
+    /// Initialize logger with configuration
+    pub fn init(config: NewRelicConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let mut headers = HeaderMap::new();
+        let mut api_key = HeaderValue::from_str(&config.api_key).map_err(|_| {
+            ServiceLoggingError::ConfigError("Invalid New Relic api key".to_string())
+        })?;
+        api_key.set_sensitive(true);
+        headers.insert("Api-Key", api_key);
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let client = http_client(headers)?;
+        Ok(Box::new(Self {
+            endpoint: config.endpoint,
+            client,
+        }))
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for NewRelicLogger {
+    /// Send logs to New Relic.
+    /// May return error if there was a problem sending.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let logs = entries
+            .iter()
+            .map(log_object)
+            .collect::<Result<Vec<Value>, serde_json::Error>>()?;
+        let payload = json!([{
+            "common": { "attributes": { "subsystem": sub } },
+            "logs": logs,
+        }]);
+        let body = serde_json::to_vec(&payload)?;
+        let mut req = self.client.post(&self.endpoint);
+        let body = if body.len() > GZIP_THRESHOLD {
+            req = req.header(CONTENT_ENCODING, "gzip");
+            gzip(&body).map_err(|e| ServiceLoggingError::Other(e.to_string()))?
+        } else {
+            body
+        };
+        if body.len() > MAX_PAYLOAD_SIZE {
+            return Err(ServiceLoggingError::PayloadTooLarge {
+                size: body.len(),
+                limit: MAX_PAYLOAD_SIZE,
+                request_id: None,
+            });
+        }
+        let size = body.len();
+        let resp = req.body(body).send().await?;
+        check_status_sized(resp, size, MAX_PAYLOAD_SIZE).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+// Logger for OpenTelemetry collectors (feature "otel"), using OTLP/HTTP with JSON encoding
+// https://opentelemetry.io/docs/specs/otlp/#otlphttp
+use crate::logging::{check_status, http_client};
+use crate::{LogEntry, Logger, ServiceLoggingError, Severity};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+/// Configuration parameters for OpenTelemetry
+#[derive(Clone, Debug, Default)]
+pub struct OtelConfig {
+    /// Collector base url, e.g., `http://localhost:4318`. `/v1/logs` is appended
+    /// unless the url already ends with it.
+    pub exporter_endpoint: String,
+}
+
+/// Implementation of Logger for OpenTelemetry collectors, using OTLP/HTTP JSON.
+/// Each call to `send` posts one `ResourceLogs` with `service.name` set to the subsystem.
+#[derive(Debug)]
+pub struct OtelLogger {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl Severity {
+    // OTLP SeverityNumber
+    fn otel_severity_number(&self) -> u8 {
+        match self {
+            Severity::Debug => 5,
+            Severity::Verbose => 6,
+            Severity::Info => 9,
+            Severity::Warning => 13,
+            Severity::Error => 17,
+            Severity::Critical => 21,
+        }
+    }
+}
+
+pub(crate) fn string_value(s: &str) -> Value {
+    json!({ "stringValue": s })
+}
+
+pub(crate) fn attribute(key: &str, value: Value) -> Value {
+    json!({ "key": key, "value": value })
+}
+
+// OTLP LogRecord for the entry
+fn log_record(entry: &LogEntry) -> Value {
+    let nanos = match entry.timestamp_us {
+        Some(us) => us * 1000,
+        None => entry.timestamp * 1_000_000,
+    };
+    let mut attributes = Vec::new();
+    let strings = [
+        ("category", &entry.category),
+        ("class_name", &entry.class_name),
+        ("thread.id", &entry.thread_id),
+        ("code.function", &entry.method_name),
+        ("code.filepath", &entry.source_file),
+        ("code.namespace", &entry.source_module),
+        ("deployment.environment", &entry.environment),
+        ("service.version", &entry.app_version),
+    ];
+    for (key, val) in strings.iter() {
+        if let Some(val) = val {
+            attributes.push(attribute(key, string_value(val)));
+        }
+    }
+    if let Some(line) = entry.source_line {
+        // int64 values are encoded as strings in OTLP JSON
+        attributes.push(attribute(
+            "code.lineno",
+            json!({ "intValue": line.to_string() }),
+        ));
+    }
+    for (key, val) in entry.custom_fields.iter() {
+        attributes.push(attribute(key, string_value(val)));
+    }
+    for (key, val) in entry.metrics.iter() {
+        attributes.push(attribute(key, json!({ "doubleValue": val })));
+    }
+    if !entry.tags.is_empty() {
+        let values: Vec<Value> = entry.tags.iter().map(|t| string_value(t)).collect();
+        attributes.push(attribute(
+            "tags",
+            json!({ "arrayValue": { "values": values } }),
+        ));
+    }
+
+    let mut record = json!({
+        "timeUnixNano": nanos.to_string(),
+        "severityNumber": entry.severity.otel_severity_number(),
+        "severityText": entry.severity.to_string().to_uppercase(),
+        "body": string_value(&entry.text),
+        "attributes": attributes,
+    });
+    if let Some(trace_id) = &entry.trace_id {
+        record["traceId"] = trace_id.clone().into();
+    }
+    if let Some(span_id) = &entry.span_id {
+        record["spanId"] = span_id.clone().into();
+    }
+    record
+}
+
+// appends `/v1/logs` to the base url, unless it already ends with it
+pub(crate) fn logs_url(endpoint: &str) -> String {
+    let base = endpoint.trim_end_matches('/');
+    if base.ends_with("/v1/logs") {
+        base.to_string()
+    } else {
+        format!("{}/v1/logs", base)
+    }
+}
+
+// OTLP `ExportLogsServiceRequest` json with one ResourceLogs
+pub(crate) fn export_logs_request(resource_attributes: Vec<Value>, entries: &[LogEntry]) -> Value {
+    let records: Vec<Value> = entries.iter().map(log_record).collect();
+    json!({
+        "resourceLogs": [{
+            "resource": {
+                "attributes": resource_attributes,
+            },
+            "scopeLogs": [{
+                "scope": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "logRecords": records,
+            }],
+        }]
+    })
+}
+
+impl OtelLogger {
+    /// Initialize logger with configuration
+    pub fn init(config: OtelConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let url = logs_url(&config.exporter_endpoint);
+        let client = http_client(reqwest::header::HeaderMap::new())?;
+        Ok(Box::new(Self { url, client }))
+    }
+
+    /// Returns the OTLP `ExportLogsServiceRequest` json for the entries
+    pub fn export_request(sub: &str, entries: &[LogEntry]) -> Value {
+        export_logs_request(vec![attribute("service.name", string_value(sub))], entries)
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for OtelLogger {
+    /// Send logs to the collector.
+    /// May return error if there was a problem sending.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let resp = self
+            .client
+            .post(&self.url)
+            .json(&Self::export_request(sub, &entries))
+            .send()
+            .await?;
+        check_status(resp).await?;
+        Ok(())
+    }
+}
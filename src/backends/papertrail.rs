@@ -0,0 +1,122 @@
+// Logger for Papertrail (feature "papertrail"), using syslog over TLS (RFC 5425)
+// https://www.papertrail.com/help/configuring-remote-syslog-from-embedded-or-proprietary-systems/
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use std::sync::{Mutex, MutexGuard};
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+use tokio_native_tls::native_tls;
+use tokio_native_tls::{TlsConnector, TlsStream};
+
+/// Configuration parameters for Papertrail
+#[derive(Clone, Debug, Default)]
+pub struct PapertrailConfig {
+    /// Log destination host, e.g., `logs.papertrailapp.com`
+    pub host: String,
+    /// Log destination port
+    pub port: u16,
+    /// System name, used as the syslog HOSTNAME
+    pub system_name: String,
+    /// Optional additional trusted root certificate (PEM), for servers with private certificates
+    pub ca_certificate: Option<String>,
+}
+
+/// Implementation of Logger for [Papertrail](https://www.papertrail.com/).
+/// Entries are sent as RFC 5424 syslog messages, framed with octet counting,
+/// over a TLS connection that is kept open between calls to `send`.
+/// The subsystem is used as the syslog APP-NAME. Not available on wasm32 targets.
+pub struct PapertrailLogger {
+    config: PapertrailConfig,
+    connector: TlsConnector,
+    proc_id: String,
+    conn: Mutex<Option<TlsStream<TcpStream>>>,
+}
+
+impl std::fmt::Debug for PapertrailLogger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PapertrailLogger")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl PapertrailLogger {
+    /// Initialize logger with configuration. The connection is opened on the first `send`
+    pub fn init(config: PapertrailConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let mut builder = native_tls::TlsConnector::builder();
+        if let Some(pem) = &config.ca_certificate {
+            let cert = native_tls::Certificate::from_pem(pem.as_bytes())
+                .map_err(|e| ServiceLoggingError::ConfigError(e.to_string()))?;
+            builder.add_root_certificate(cert);
+        }
+        let connector = builder
+            .build()
+            .map_err(|e| ServiceLoggingError::ConfigError(e.to_string()))?
+            .into();
+        Ok(Box::new(Self {
+            config,
+            connector,
+            proc_id: std::process::id().to_string(),
+            conn: Mutex::new(None),
+        }))
+    }
+
+    async fn connect(&self) -> Result<TlsStream<TcpStream>, ServiceLoggingError> {
+        let tcp = TcpStream::connect((self.config.host.as_str(), self.config.port))
+            .await
+            .map_err(network_error)?;
+        self.connector
+            .connect(&self.config.host, tcp)
+            .await
+            .map_err(network_error)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<TlsStream<TcpStream>>> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // syslog frames for the entries: "MSG-LEN SP SYSLOG-MSG"
+    fn frames(&self, sub: &str, entries: &[LogEntry]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for entry in entries.iter() {
+            let msg = entry.to_syslog_line(&self.config.system_name, sub, &self.proc_id);
+            buf.extend_from_slice(format!("{} {}", msg.len(), msg).as_bytes());
+        }
+        buf
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for PapertrailLogger {
+    /// Send logs to Papertrail. If writing to an existing connection fails,
+    /// the connection is re-established and the write is retried once.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let buf = self.frames(sub, &entries);
+        let existing = self.lock().take();
+        let mut stream = match existing {
+            Some(mut stream) => match write_all(&mut stream, &buf).await {
+                Ok(()) => {
+                    *self.lock() = Some(stream);
+                    return Ok(());
+                }
+                Err(_) => self.connect().await?,
+            },
+            None => self.connect().await?,
+        };
+        write_all(&mut stream, &buf).await.map_err(network_error)?;
+        *self.lock() = Some(stream);
+        Ok(())
+    }
+}
+
+fn network_error(e: impl std::fmt::Display) -> ServiceLoggingError {
+    ServiceLoggingError::NetworkError(e.to_string())
+}
+
+async fn write_all(stream: &mut TlsStream<TcpStream>, buf: &[u8]) -> std::io::Result<()> {
+    stream.write_all(buf).await?;
+    stream.flush().await
+}
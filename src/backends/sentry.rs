@@ -0,0 +1,171 @@
+// Logger for Sentry (feature "sentry"), using the envelope endpoint
+// https://develop.sentry.dev/sdk/envelopes/
+use crate::logging::{check_status, http_client};
+use crate::{LogEntry, Logger, ServiceLoggingError, Severity};
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Configuration parameters for Sentry
+#[derive(Clone, Debug, Default)]
+pub struct SentryConfig {
+    /// Project DSN, e.g., `https://<public_key>@o0.ingest.sentry.io/<project_id>`
+    pub dsn: String,
+    /// Optional environment, e.g., "production"
+    pub environment: Option<String>,
+    /// Optional release version
+    pub release: Option<String>,
+}
+
+/// Implementation of Logger for [Sentry](https://sentry.io/).
+/// Only entries with severity Error or Critical are sent; others are silently dropped.
+/// Each entry is sent as a separate Sentry event.
+#[derive(Debug)]
+pub struct SentryLogger {
+    config: SentryConfig,
+    envelope_url: String,
+    auth_header: String,
+    client: reqwest::Client,
+}
+
+// parses DSN into (envelope url, public key)
+fn parse_dsn(dsn: &str) -> Option<(String, String)> {
+    let (scheme, rest) = dsn.split_once("://")?;
+    let (key, host_path) = rest.split_once('@')?;
+    let key = key.split(':').next().unwrap_or_default();
+    let (host_path, project_id) = host_path.trim_end_matches('/').rsplit_once('/')?;
+    if key.is_empty() || host_path.is_empty() || project_id.is_empty() {
+        return None;
+    }
+    Some((
+        format!("{}://{}/api/{}/envelope/", scheme, host_path, project_id),
+        key.to_string(),
+    ))
+}
+
+// returns 32 hex digit event id, unique within the process
+fn event_id(entry: &LogEntry) -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut hasher = DefaultHasher::new();
+    crate::time::current_time_micros().hash(&mut hasher);
+    entry.text.hash(&mut hasher);
+    let hi = hasher.finish();
+    COUNTER.fetch_add(1, Ordering::Relaxed).hash(&mut hasher);
+    format!("{:016x}{:016x}", hi, hasher.finish())
+}
+
+impl SentryLogger {
+    /// Initialize logger with configuration. Returns error if the DSN is invalid
+    pub fn init(config: SentryConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let (envelope_url, key) = parse_dsn(&config.dsn).ok_or_else(|| {
+            ServiceLoggingError::ConfigError(format!("Invalid Sentry DSN: {}", &config.dsn))
+        })?;
+        let auth_header = format!(
+            "Sentry sentry_version=7, sentry_key={}, sentry_client={}",
+            key,
+            crate::logging::LIB_USER_AGENT
+        );
+        let client = http_client(reqwest::header::HeaderMap::new())?;
+        Ok(Box::new(Self {
+            config,
+            envelope_url,
+            auth_header,
+            client,
+        }))
+    }
+
+    // returns Sentry event for the entry
+    fn event(&self, id: &str, entry: &LogEntry) -> Value {
+        let mut event = Map::new();
+        event.insert("event_id".into(), id.into());
+        event.insert("timestamp".into(), (entry.timestamp as f64 / 1000.0).into());
+        event.insert("platform".into(), "other".into());
+        event.insert(
+            "level".into(),
+            match entry.severity {
+                Severity::Critical => "fatal",
+                _ => "error",
+            }
+            .into(),
+        );
+        event.insert("logentry".into(), json!({ "formatted": entry.text }));
+        if let Some(class_name) = &entry.class_name {
+            event.insert("logger".into(), class_name.clone().into());
+        }
+        if let Some(environment) = entry
+            .environment
+            .as_ref()
+            .or(self.config.environment.as_ref())
+        {
+            event.insert("environment".into(), environment.clone().into());
+        }
+        if let Some(release) = entry.app_version.as_ref().or(self.config.release.as_ref()) {
+            event.insert("release".into(), release.clone().into());
+        }
+        let culprit = match (&entry.source_file, entry.source_line, &entry.method_name) {
+            (Some(file), Some(line), _) => Some(format!("{}:{}", file, line)),
+            (Some(file), None, _) => Some(file.clone()),
+            (None, _, Some(method)) => Some(method.clone()),
+            _ => None,
+        };
+        if let Some(culprit) = culprit {
+            event.insert("culprit".into(), culprit.into());
+        }
+        let mut tags = Map::new();
+        if let Some(category) = &entry.category {
+            tags.insert("category".into(), category.clone().into());
+        }
+        for tag in entry.tags.iter() {
+            tags.insert(tag.clone(), "true".into());
+        }
+        if !tags.is_empty() {
+            event.insert("tags".into(), Value::Object(tags));
+        }
+        if !entry.custom_fields.is_empty() {
+            event.insert("extra".into(), json!(entry.custom_fields));
+        }
+        event.insert(
+            "fingerprint".into(),
+            json!([entry.text, entry.class_name.as_deref().unwrap_or_default()]),
+        );
+        Value::Object(event)
+    }
+
+    // returns envelope containing one event
+    fn envelope(&self, entry: &LogEntry) -> String {
+        let id = event_id(entry);
+        let event = self.event(&id, entry).to_string();
+        let header = json!({
+            "event_id": id,
+            "dsn": self.config.dsn,
+            "sent_at": crate::time::format_rfc3339(crate::time::current_time_millis()),
+        });
+        let item_header = json!({ "type": "event", "length": event.len() });
+        format!("{}\n{}\n{}\n", header, item_header, event)
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for SentryLogger {
+    /// Send Error and Critical entries to Sentry as events.
+    /// May return error if there was a problem sending.
+    async fn send(&self, _sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        for entry in entries.iter().filter(|e| e.severity >= Severity::Error) {
+            let resp = self
+                .client
+                .post(&self.envelope_url)
+                .header("X-Sentry-Auth", &self.auth_header)
+                .header(
+                    reqwest::header::CONTENT_TYPE,
+                    "application/x-sentry-envelope",
+                )
+                .body(self.envelope(entry))
+                .send()
+                .await?;
+            check_status(resp).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,205 @@
+// Logger that stores entries in a local SQLite database (feature "sqlite")
+use crate::{LogEntry, Logger, ServiceLoggingError, Severity};
+use async_trait::async_trait;
+use rusqlite::{params, Connection, Row};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Configuration parameters for SqliteLogger
+#[derive(Clone, Debug, Default)]
+pub struct SqliteConfig {
+    /// Database file path. The file is created if it doesn't exist
+    pub db_path: PathBuf,
+    /// Table name, created if it doesn't exist. Must contain only letters, digits, and '_'
+    pub table_name: String,
+    /// Optional maximum number of rows. When exceeded, the oldest rows are deleted
+    pub max_rows: Option<u64>,
+}
+
+/// Implementation of Logger that inserts entries into a SQLite table, for local persistence.
+/// `custom_fields`, `tags`, and `metrics` are stored as json.
+/// Not available on wasm32 targets.
+#[derive(Debug)]
+pub struct SqliteLogger {
+    table: String,
+    max_rows: Option<u64>,
+    conn: Mutex<Connection>,
+}
+
+const COLUMNS: &str = "timestamp, timestamp_us, severity, text, category, class_name, \
+    method_name, thread_id, source_file, source_line, source_module, custom_fields, tags, \
+    trace_id, span_id, environment, app_version, metrics, subsystem, hostname";
+
+impl SqliteLogger {
+    /// Opens (or creates) the database and table
+    pub fn new(config: SqliteConfig) -> Result<SqliteLogger, rusqlite::Error> {
+        if config.table_name.is_empty()
+            || !config
+                .table_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(rusqlite::Error::InvalidParameterName(config.table_name));
+        }
+        let conn = Connection::open(&config.db_path)?;
+        conn.execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {table} (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                timestamp INTEGER NOT NULL,
+                timestamp_us INTEGER,
+                severity INTEGER NOT NULL,
+                text TEXT NOT NULL,
+                category TEXT,
+                class_name TEXT,
+                method_name TEXT,
+                thread_id TEXT,
+                source_file TEXT,
+                source_line INTEGER,
+                source_module TEXT,
+                custom_fields TEXT NOT NULL,
+                tags TEXT NOT NULL,
+                trace_id TEXT,
+                span_id TEXT,
+                environment TEXT,
+                app_version TEXT,
+                metrics TEXT NOT NULL,
+                subsystem TEXT NOT NULL,
+                hostname TEXT
+            );
+            CREATE INDEX IF NOT EXISTS {table}_timestamp ON {table} (timestamp);",
+            table = config.table_name
+        ))?;
+        // tables created by earlier versions don't have a hostname column
+        let has_hostname: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = 'hostname'",
+            params![config.table_name],
+            |row| row.get(0),
+        )?;
+        if !has_hostname {
+            conn.execute_batch(&format!(
+                "ALTER TABLE {} ADD COLUMN hostname TEXT",
+                config.table_name
+            ))?;
+        }
+        Ok(SqliteLogger {
+            table: config.table_name,
+            max_rows: config.max_rows,
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Initialize logger with configuration
+    pub fn init(config: SqliteConfig) -> Result<Box<dyn Logger + Send>, rusqlite::Error> {
+        Ok(Box::new(Self::new(config)?))
+    }
+
+    /// Returns stored entries with severity at least `min_severity` and timestamp
+    /// at or after `since_ms`, oldest first
+    pub fn query(
+        &self,
+        min_severity: Severity,
+        since_ms: u64,
+    ) -> Result<Vec<LogEntry>, rusqlite::Error> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM {} WHERE severity >= ?1 AND timestamp >= ?2 ORDER BY id",
+            COLUMNS, self.table
+        ))?;
+        let rows = stmt.query_map(params![min_severity as u8, since_ms as i64], from_row)?;
+        rows.collect()
+    }
+
+    fn insert(&self, sub: &str, entries: &[LogEntry]) -> Result<(), ServiceLoggingError> {
+        let mut conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let tx = conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(&format!(
+                "INSERT INTO {} ({}) VALUES \
+                (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)",
+                self.table, COLUMNS
+            ))?;
+            for e in entries.iter() {
+                stmt.execute(params![
+                    e.timestamp as i64,
+                    e.timestamp_us.map(|us| us as i64),
+                    e.severity.clone() as u8,
+                    e.text,
+                    e.category,
+                    e.class_name,
+                    e.method_name,
+                    e.thread_id,
+                    e.source_file,
+                    e.source_line,
+                    e.source_module,
+                    serde_json::to_string(&e.custom_fields)?,
+                    serde_json::to_string(&e.tags)?,
+                    e.trace_id,
+                    e.span_id,
+                    e.environment,
+                    e.app_version,
+                    serde_json::to_string(&e.metrics)?,
+                    sub,
+                    e.hostname,
+                ])?;
+            }
+            if let Some(max_rows) = self.max_rows {
+                // delete oldest rows beyond the limit
+                stmt = tx.prepare(&format!(
+                    "DELETE FROM {table} WHERE id NOT IN \
+                    (SELECT id FROM {table} ORDER BY id DESC LIMIT ?1)",
+                    table = self.table
+                ))?;
+                stmt.execute(params![max_rows as i64])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+// parses json column, which may be empty
+fn json_column<T: serde::de::DeserializeOwned + Default>(
+    row: &Row<'_>,
+    idx: usize,
+) -> rusqlite::Result<T> {
+    let s: String = row.get(idx)?;
+    serde_json::from_str(&s).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+fn from_row(row: &Row<'_>) -> rusqlite::Result<LogEntry> {
+    Ok(LogEntry {
+        timestamp: row.get::<_, i64>(0)? as u64,
+        timestamp_us: row.get::<_, Option<i64>>(1)?.map(|us| us as u64),
+        severity: Severity::from_u8(row.get(2)?),
+        text: row.get(3)?,
+        category: row.get(4)?,
+        class_name: row.get(5)?,
+        method_name: row.get(6)?,
+        thread_id: row.get(7)?,
+        source_file: row.get(8)?,
+        source_line: row.get(9)?,
+        source_module: row.get(10)?,
+        custom_fields: json_column(row, 11)?,
+        tags: json_column(row, 12)?,
+        trace_id: row.get(13)?,
+        span_id: row.get(14)?,
+        environment: row.get(15)?,
+        app_version: row.get(16)?,
+        metrics: json_column(row, 17)?,
+        hostname: row.get(19)?,
+    })
+}
+
+#[async_trait(?Send)]
+impl Logger for SqliteLogger {
+    /// Insert entries into the table, then delete the oldest rows if `max_rows` is exceeded.
+    /// May return error if there was a database error.
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.insert(sub, &entries)
+    }
+}
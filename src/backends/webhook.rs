@@ -0,0 +1,124 @@
+// Logger for generic http webhooks (feature "webhook")
+use crate::logging::{check_status_sized, http_client};
+use crate::{LogEntry, LogEntrySerializer, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use std::sync::Arc;
+
+/// Request body format for WebhookLogger
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WebhookBodyFormat {
+    /// One request per send, with a json array of entries (`application/json`)
+    #[default]
+    JsonArray,
+    /// One request per send, with one json entry per line (`application/x-ndjson`)
+    JsonLines,
+    /// One request per entry, with a single json object (`application/json`)
+    SingleEntry,
+}
+
+/// Configuration parameters for a webhook
+#[derive(Clone, Debug, Default)]
+pub struct WebhookConfig {
+    /// Url for POST requests
+    pub url: String,
+    /// Additional headers for each request, e.g., `Authorization`
+    pub headers: Vec<(String, String)>,
+    /// Request body format
+    pub body_template: WebhookBodyFormat,
+    /// Serializer for entries. If None, entries are serialized with [to_json](LogEntry::to_json)
+    pub serializer: Option<Arc<dyn LogEntrySerializer>>,
+}
+
+/// Implementation of Logger that POSTs entries as json to a url
+#[derive(Debug)]
+pub struct WebhookLogger {
+    url: String,
+    format: WebhookBodyFormat,
+    serializer: Option<Arc<dyn LogEntrySerializer>>,
+    client: reqwest::Client,
+}
+
+impl WebhookLogger {
+    /// Initialize logger with configuration. Returns error if a header name or value is invalid
+    pub fn init(config: WebhookConfig) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in config.headers.iter() {
+            let invalid =
+                || ServiceLoggingError::ConfigError(format!("Invalid webhook header: {}", name));
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid())?,
+                HeaderValue::from_str(value).map_err(|_| invalid())?,
+            );
+        }
+        let client = http_client(headers)?;
+        Ok(Box::new(Self {
+            url: config.url,
+            format: config.body_template,
+            serializer: config.serializer,
+            client,
+        }))
+    }
+
+    async fn post(
+        &self,
+        content_type: &'static str,
+        body: String,
+    ) -> Result<(), ServiceLoggingError> {
+        let size = body.len();
+        let resp = self
+            .client
+            .post(&self.url)
+            .header(CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await?;
+        check_status_sized(resp, size, 0).await?;
+        Ok(())
+    }
+
+    // serializes an entry with the configured serializer
+    fn entry_json(&self, entry: &LogEntry) -> Result<String, ServiceLoggingError> {
+        match &self.serializer {
+            Some(serializer) => Ok(serializer.serialize(entry, "webhook")?.to_string()),
+            None => Ok(entry.to_json()?),
+        }
+    }
+
+    fn entries_json(&self, entries: &[LogEntry]) -> Result<Vec<String>, ServiceLoggingError> {
+        entries.iter().map(|e| self.entry_json(e)).collect()
+    }
+
+    // sends a single entry as a json object
+    async fn send_one(&self, entry: &LogEntry) -> Result<(), ServiceLoggingError> {
+        self.post("application/json", self.entry_json(entry)?).await
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for WebhookLogger {
+    /// Send logs to the webhook.
+    /// May return error if there was a problem sending. In `SingleEntry` mode,
+    /// sending stops at the first error.
+    async fn send(&self, _sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        match self.format {
+            WebhookBodyFormat::JsonArray => {
+                let body = format!("[{}]", self.entries_json(&entries)?.join(","));
+                self.post("application/json", body).await
+            }
+            WebhookBodyFormat::JsonLines => {
+                let body = self.entries_json(&entries)?.join("\n");
+                self.post("application/x-ndjson", body).await
+            }
+            WebhookBodyFormat::SingleEntry => {
+                for entry in entries.iter() {
+                    self.send_one(entry).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
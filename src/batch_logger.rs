@@ -0,0 +1,153 @@
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+type Buffer = BTreeMap<String, Vec<LogEntry>>;
+
+// default limit on buffered entries, for all subsystems
+const DEFAULT_MAX_BUFFERED: usize = 10_000;
+
+/// Logger that buffers entries for each subsystem, and sends them to an inner logger
+/// in batches of at least `batch_size` entries. Call [flush](BatchLogger::flush) or
+/// [shutdown](Logger::shutdown) to send entries remaining in the buffer.
+/// Batches that the inner logger fails to send are kept in the buffer, and sent with the next batch.
+/// At most 10,000 entries are buffered (see [with_max_buffered](BatchLogger::with_max_buffered));
+/// past that, the oldest entries are dropped.
+pub struct BatchLogger {
+    inner: Box<dyn Logger + Send>,
+    batch_size: usize,
+    max_buffered: usize,
+    buffer: Mutex<Buffer>,
+    dropped: AtomicU64,
+}
+
+impl BatchLogger {
+    /// Constructs logger that sends batches to `inner`
+    pub fn new(inner: Box<dyn Logger + Send>, batch_size: usize) -> Self {
+        Self {
+            inner,
+            batch_size,
+            max_buffered: DEFAULT_MAX_BUFFERED.max(batch_size),
+            buffer: Mutex::new(BTreeMap::new()),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets the maximum number of buffered entries, for all subsystems. When the buffer is full,
+    /// the oldest entries are dropped. Values less than `batch_size` are raised to `batch_size`
+    pub fn with_max_buffered(mut self, max_buffered: usize) -> Self {
+        self.max_buffered = max_buffered.max(self.batch_size);
+        self
+    }
+
+    /// Returns the number of buffered entries
+    pub fn buffered(&self) -> usize {
+        self.lock().values().map(Vec::len).sum()
+    }
+
+    /// Returns the number of entries dropped because the buffer was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Sends all buffered entries to the inner logger. Every subsystem is attempted;
+    /// entries that couldn't be sent stay in the buffer, and the first error is returned
+    pub async fn flush(&self) -> Result<(), ServiceLoggingError> {
+        let buffer = std::mem::take(&mut *self.lock());
+        let mut result = Ok(());
+        for (sub, entries) in buffer {
+            if let Err(e) = self.send_batch(&sub, entries).await {
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    // sends a batch to the inner logger. If that fails, returns the batch to the
+    // buffer, ahead of any entries added since it was removed
+    async fn send_batch(&self, sub: &str, batch: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        let unsent = batch.clone();
+        let result = self.inner.send(sub, batch).await;
+        if result.is_err() {
+            let mut buffer = self.lock();
+            let buffered = buffer.entry(sub.to_string()).or_default();
+            buffered.splice(0..0, unsent);
+            self.drop_oldest(&mut buffer);
+        }
+        result
+    }
+
+    // drops the oldest entries past max_buffered. Each subsystem's entries are in the
+    // order they were sent; the subsystem whose first entry has the earliest timestamp loses one
+    fn drop_oldest(&self, buffer: &mut Buffer) {
+        let total: usize = buffer.values().map(Vec::len).sum();
+        let excess = total.saturating_sub(self.max_buffered);
+        if excess == 0 {
+            return;
+        }
+        // number of entries to drop from the front of each subsystem's entries
+        let mut counts = vec![0; buffer.len()];
+        for _ in 0..excess {
+            let oldest = buffer
+                .values()
+                .enumerate()
+                .filter(|(i, entries)| counts[*i] < entries.len())
+                .min_by_key(|(i, entries)| entries[counts[*i]].timestamp)
+                .map(|(i, _)| i);
+            if let Some(i) = oldest {
+                counts[i] += 1;
+            }
+        }
+        for (entries, count) in buffer.values_mut().zip(counts) {
+            entries.drain(..count);
+        }
+        buffer.retain(|_, entries| !entries.is_empty());
+        self.dropped.fetch_add(excess as u64, Ordering::Relaxed);
+    }
+
+    // lock the buffer. A panic in another thread doesn't lose buffered entries
+    fn lock(&self) -> MutexGuard<'_, Buffer> {
+        self.buffer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for BatchLogger {
+    /// Adds entries to the buffer, and sends the subsystem's buffered entries
+    /// if there are at least `batch_size`. Returns Ok once entries are buffered: if the batch
+    /// can't be sent, it stays in the buffer, and errors are returned by
+    /// [flush](BatchLogger::flush) and [shutdown](Logger::shutdown)
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        let batch = {
+            let mut buffer = self.lock();
+            buffer.entry(sub.to_string()).or_default().extend(entries);
+            self.drop_oldest(&mut buffer);
+            if buffer.get(sub).map_or(0, Vec::len) >= self.batch_size {
+                buffer.remove(sub)
+            } else {
+                None
+            }
+        };
+        if let Some(batch) = batch {
+            // on failure, the batch is back in the buffer, to be sent with the next batch or flush
+            let _ = self.send_batch(sub, batch).await;
+        }
+        Ok(())
+    }
+
+    async fn health_check(&self) -> Result<Duration, ServiceLoggingError> {
+        self.inner.health_check().await
+    }
+
+    /// Sends buffered entries, then shuts down the inner logger.
+    /// If any entries can't be sent, returns the error without shutting down the inner logger
+    async fn shutdown(&self) -> Result<(), ServiceLoggingError> {
+        self.flush().await?;
+        self.inner.shutdown().await
+    }
+}
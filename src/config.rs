@@ -0,0 +1,213 @@
+use crate::CoralogixConfig;
+use std::fmt;
+
+/// Error in logger configuration
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// Required environment variable is not set. Contains the variable name
+    MissingVar(String),
+    /// Required environment variable is empty. Contains the variable name
+    EmptyVar(String),
+    /// Required field is empty. Contains the field name
+    EmptyField(String),
+    /// Field is longer than the maximum length
+    FieldTooLong {
+        /// Field name
+        field: String,
+        /// Maximum length, in bytes
+        max: usize,
+    },
+    /// Endpoint is not a valid https url. Contains a description of the problem
+    InvalidUrl(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingVar(name) => write!(f, "environment variable {} is not set", name),
+            ConfigError::EmptyVar(name) => write!(f, "environment variable {} is empty", name),
+            ConfigError::EmptyField(name) => write!(f, "{} is empty", name),
+            ConfigError::FieldTooLong { field, max } => {
+                write!(f, "{} is longer than {} bytes", field, max)
+            }
+            ConfigError::InvalidUrl(msg) => write!(f, "invalid endpoint: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Configuration parameters for Coralogix service, with owned values.
+/// Use [as_config](CoralogixConfigOwned::as_config) to initialize a [CoralogixLogger](crate::CoralogixLogger)
+#[derive(Clone, Debug, Default)]
+pub struct CoralogixConfigOwned {
+    /// API key, provided by Coralogix
+    pub api_key: String,
+    /// Application name, included as a feature for all log messages
+    pub application_name: String,
+    /// URL prefix for service invocation
+    pub endpoint: String,
+    /// Optional computer name, included as a feature for all log messages
+    pub computer_name: Option<String>,
+}
+
+impl CoralogixConfigOwned {
+    /// Returns config borrowing from self
+    pub fn as_config(&self) -> CoralogixConfig<'_> {
+        CoralogixConfig {
+            api_key: &self.api_key,
+            application_name: &self.application_name,
+            endpoint: &self.endpoint,
+            computer_name: self.computer_name.as_deref(),
+            ..Default::default()
+        }
+    }
+}
+
+// reads required environment variable
+fn required_var(name: &str) -> Result<String, ConfigError> {
+    match std::env::var(name) {
+        Ok(val) if val.trim().is_empty() => Err(ConfigError::EmptyVar(name.to_string())),
+        Ok(val) => Ok(val),
+        Err(_) => Err(ConfigError::MissingVar(name.to_string())),
+    }
+}
+
+// maximum lengths of CoralogixConfig fields, in bytes
+const MAX_KEY_LEN: usize = 256;
+const MAX_NAME_LEN: usize = 256;
+const MAX_ENDPOINT_LEN: usize = 2048;
+
+// checks that the endpoint is an https url. http is allowed for loopback addresses, for testing
+fn validate_url(endpoint: &str) -> Result<(), ConfigError> {
+    let url = reqwest::Url::parse(endpoint).map_err(|e| ConfigError::InvalidUrl(e.to_string()))?;
+    let loopback = match url.host_str() {
+        Some("localhost") => true,
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .map(|addr| addr.is_loopback())
+            .unwrap_or(false),
+        None => false,
+    };
+    match url.scheme() {
+        "https" => Ok(()),
+        "http" if loopback => Ok(()),
+        scheme => Err(ConfigError::InvalidUrl(format!(
+            "scheme must be https, not {}",
+            scheme
+        ))),
+    }
+}
+
+/// Coralogix account region, for the logs api endpoint. Set [CoralogixConfig::region]
+/// instead of `endpoint` to use the region's endpoint
+///
+/// | Variant     | Coralogix region | Location  | Domain               |
+/// |-------------|------------------|-----------|----------------------|
+/// | `UsEast`    | US1              | Ohio      | `coralogix.us`       |
+/// | `UsWest`    | US2              | Oregon    | `cx498.coralogix.com`|
+/// | `Europe`    | EU1              | Ireland   | `coralogix.com`      |
+/// | `Europe2`   | EU2              | Stockholm | `eu2.coralogix.com`  |
+/// | `India`     | AP1              | Mumbai    | `app.coralogix.in`   |
+/// | `Singapore` | AP2              | Singapore | `coralogixsg.com`    |
+/// | `Indonesia` | AP3              | Jakarta   | `ap3.coralogix.com`  |
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoralogixRegion {
+    /// US1
+    UsEast,
+    /// US2
+    UsWest,
+    /// EU1
+    Europe,
+    /// EU2
+    Europe2,
+    /// AP1
+    India,
+    /// AP2
+    Singapore,
+    /// AP3
+    Indonesia,
+}
+
+impl CoralogixRegion {
+    /// Returns the logs api url for the region
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            CoralogixRegion::UsEast => "https://api.coralogix.us/api/v1/logs",
+            CoralogixRegion::UsWest => "https://api.cx498.coralogix.com/api/v1/logs",
+            CoralogixRegion::Europe => "https://api.coralogix.com/api/v1/logs",
+            CoralogixRegion::Europe2 => "https://api.eu2.coralogix.com/api/v1/logs",
+            CoralogixRegion::India => "https://api.app.coralogix.in/api/v1/logs",
+            CoralogixRegion::Singapore => "https://api.coralogixsg.com/api/v1/logs",
+            CoralogixRegion::Indonesia => "https://api.ap3.coralogix.com/api/v1/logs",
+        }
+    }
+}
+
+impl<'config> CoralogixConfig<'config> {
+    /// Returns `endpoint`, or, if `endpoint` is empty and `region` is set, the region's endpoint
+    pub fn endpoint_url(&self) -> &'config str {
+        match self.region {
+            Some(region) if self.endpoint.is_empty() => region.endpoint(),
+            _ => self.endpoint,
+        }
+    }
+
+    /// Checks that `api_key` and `application_name` are non-empty, that the
+    /// [endpoint](CoralogixConfig::endpoint_url) is an `https://` url
+    /// (`http://` is allowed for localhost), and that fields are within
+    /// maximum lengths: 256 bytes for `api_key`, `application_name`, and `computer_name`,
+    /// and 2048 bytes for `endpoint`. Returns all errors found.
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        let fields = [
+            ("api_key", Some(self.api_key), MAX_KEY_LEN),
+            (
+                "application_name",
+                Some(self.application_name),
+                MAX_NAME_LEN,
+            ),
+            ("computer_name", self.computer_name, MAX_NAME_LEN),
+            ("endpoint", Some(self.endpoint_url()), MAX_ENDPOINT_LEN),
+        ];
+        for (field, val, max) in fields.iter() {
+            match val {
+                Some(val) if val.len() > *max => errors.push(ConfigError::FieldTooLong {
+                    field: field.to_string(),
+                    max: *max,
+                }),
+                Some("") if *field != "computer_name" => {
+                    errors.push(ConfigError::EmptyField(field.to_string()))
+                }
+                _ => {}
+            }
+        }
+        let endpoint = self.endpoint_url();
+        if !endpoint.is_empty() && endpoint.len() <= MAX_ENDPOINT_LEN {
+            if let Err(e) = validate_url(endpoint) {
+                errors.push(e);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Reads configuration from environment variables `CORALOGIX_API_KEY`, `CORALOGIX_APP_NAME`,
+    /// `CORALOGIX_ENDPOINT`, and (optional) `CORALOGIX_COMPUTER_NAME`.
+    /// Returns an error naming the first required variable that is missing or empty.
+    pub fn from_env() -> Result<CoralogixConfigOwned, ConfigError> {
+        Ok(CoralogixConfigOwned {
+            api_key: required_var("CORALOGIX_API_KEY")?,
+            application_name: required_var("CORALOGIX_APP_NAME")?,
+            endpoint: required_var("CORALOGIX_ENDPOINT")?,
+            computer_name: std::env::var("CORALOGIX_COMPUTER_NAME")
+                .ok()
+                .filter(|s| !s.is_empty()),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Logger that adds a fixed set of fields to the `custom_fields` of every entry it sends.
+/// Fields already set on an entry are not overwritten.
+///
+/// ```
+/// # async fn run() {
+/// use service_logging::{log, ContextualLogger, LogQueue, Logger, Severity, VecLogger};
+/// use std::collections::BTreeMap;
+/// let (inner, handle) = VecLogger::new();
+/// let mut context = BTreeMap::new();
+/// context.insert("region".to_string(), "us-east-1".to_string());
+/// let logger = ContextualLogger::new(inner, context);
+///
+/// let mut lq = LogQueue::default();
+/// log!(lq, Severity::Info, text: "hello");
+/// logger.send("sub", lq.take()).await.unwrap();
+/// assert!(handle.has_entry_matching(|e| e.custom_fields["region"] == "us-east-1"));
+/// # }
+/// ```
+pub struct ContextualLogger {
+    inner: Box<dyn Logger + Send>,
+    context: BTreeMap<String, String>,
+}
+
+impl ContextualLogger {
+    /// Constructs logger that adds `context` to entries, then sends them to `inner`
+    pub fn new(inner: Box<dyn Logger + Send>, context: BTreeMap<String, String>) -> Self {
+        Self { inner, context }
+    }
+
+    /// Returns the fields added to each entry
+    pub fn context(&self) -> &BTreeMap<String, String> {
+        &self.context
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for ContextualLogger {
+    /// Adds context fields to each entry, then sends them to the inner logger
+    async fn send(
+        &self,
+        sub: &'_ str,
+        mut entries: Vec<LogEntry>,
+    ) -> Result<(), ServiceLoggingError> {
+        for entry in entries.iter_mut() {
+            entry.add_missing_fields(&self.context);
+        }
+        self.inner.send(sub, entries).await
+    }
+
+    async fn health_check(&self) -> Result<Duration, ServiceLoggingError> {
+        self.inner.health_check().await
+    }
+
+    async fn shutdown(&self) -> Result<(), ServiceLoggingError> {
+        self.inner.shutdown().await
+    }
+}
@@ -0,0 +1,36 @@
+// CSV serialization
+use crate::LogQueue;
+
+// quote field if it contains a delimiter, quote, or line break (RFC 4180)
+fn csv_field(val: &str) -> String {
+    if val.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", val.replace('"', "\"\""))
+    } else {
+        val.to_string()
+    }
+}
+
+impl LogQueue {
+    /// Returns queued entries as CSV, with a header row of field names, and one row per entry.
+    /// `fields` are LogEntry field names (e.g., `"timestamp"`, `"severity"`, `"text"`, `"category"`)
+    /// or keys in `custom_fields`. Fields not present in an entry are empty.
+    /// Rows are separated by '\n'.
+    pub fn to_csv(&self, fields: &[&str]) -> String {
+        let mut buf = fields
+            .iter()
+            .map(|f| csv_field(f))
+            .collect::<Vec<String>>()
+            .join(",");
+        buf.push('\n');
+        for entry in self.entries.iter() {
+            let row = fields
+                .iter()
+                .map(|f| csv_field(&entry.field_string(f).unwrap_or_default()))
+                .collect::<Vec<String>>()
+                .join(",");
+            buf.push_str(&row);
+            buf.push('\n');
+        }
+        buf
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{LogEntry, LogQueue};
+use std::collections::BTreeMap;
+
+/// Default values for LogEntry fields, applied by [LogQueueWithDefaults]
+#[derive(Clone, Debug, Default)]
+pub struct LogEntryDefaults {
+    /// Default category
+    pub category: Option<String>,
+    /// Default class name
+    pub class_name: Option<String>,
+    /// Default method name
+    pub method_name: Option<String>,
+    /// Default thread id
+    pub thread_id: Option<String>,
+    /// Default trace id
+    pub trace_id: Option<String>,
+    /// Default span id
+    pub span_id: Option<String>,
+    /// Default deployment environment
+    pub environment: Option<String>,
+    /// Default application version
+    pub app_version: Option<String>,
+    /// Default host name
+    pub hostname: Option<String>,
+    /// Custom fields (e.g., region, correlation_id) added to entries that don't have the key
+    pub custom_fields: BTreeMap<String, String>,
+    /// Tags added to entries that don't already have them
+    pub tags: Vec<String>,
+}
+
+// sets the field to the default if it is None
+fn fill(field: &mut Option<String>, default: &Option<String>) {
+    if field.is_none() {
+        *field = default.clone();
+    }
+}
+
+impl LogEntryDefaults {
+    /// Sets any fields of the entry that are None to the default value,
+    /// and adds default custom fields and tags that the entry doesn't have
+    pub fn apply(&self, entry: &mut LogEntry) {
+        fill(&mut entry.category, &self.category);
+        fill(&mut entry.class_name, &self.class_name);
+        fill(&mut entry.method_name, &self.method_name);
+        fill(&mut entry.thread_id, &self.thread_id);
+        fill(&mut entry.trace_id, &self.trace_id);
+        fill(&mut entry.span_id, &self.span_id);
+        fill(&mut entry.environment, &self.environment);
+        fill(&mut entry.app_version, &self.app_version);
+        fill(&mut entry.hostname, &self.hostname);
+        entry.add_missing_fields(&self.custom_fields);
+        for tag in self.tags.iter() {
+            if !entry.tags.contains(tag) {
+                entry.tags.push(tag.clone());
+            }
+        }
+    }
+}
+
+/// Queue of log entries that fills in default values for fields as entries are added.
+/// Created with [LogQueue::with_defaults]. Usable with the [`log!`](crate::log) macro.
+///
+/// ```
+/// use service_logging::{log, LogEntryDefaults, LogQueue, Severity::Info};
+/// let mut lq = LogQueue::with_defaults(LogEntryDefaults {
+///     environment: Some("production".to_string()),
+///     ..Default::default()
+/// });
+/// log!(lq, Info, text: "hello");
+/// assert_eq!(lq.take()[0].environment.as_deref(), Some("production"));
+/// ```
+#[derive(Debug, Default)]
+pub struct LogQueueWithDefaults {
+    queue: LogQueue,
+    defaults: LogEntryDefaults,
+}
+
+impl LogQueue {
+    /// Constructs an empty queue that fills in default values for entries as they are added
+    pub fn with_defaults(defaults: LogEntryDefaults) -> LogQueueWithDefaults {
+        LogQueueWithDefaults {
+            queue: LogQueue::default(),
+            defaults,
+        }
+    }
+}
+
+impl LogQueueWithDefaults {
+    /// Applies defaults to the entry and appends it to the queue
+    pub fn log(&mut self, mut e: LogEntry) {
+        self.defaults.apply(&mut e);
+        self.queue.log(e)
+    }
+
+    /// Returns all queued items, emptying self
+    pub fn take(&mut self) -> Vec<LogEntry> {
+        self.queue.take()
+    }
+
+    /// Returns true if there are no items to log
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Removes all log entries
+    pub fn clear(&mut self) {
+        self.queue.clear()
+    }
+
+    /// Returns the defaults
+    pub fn defaults(&self) -> &LogEntryDefaults {
+        &self.defaults
+    }
+
+    /// Returns the underlying queue
+    pub fn queue(&self) -> &LogQueue {
+        &self.queue
+    }
+
+    /// Returns the underlying queue, consuming self
+    pub fn into_inner(self) -> LogQueue {
+        self.queue
+    }
+}
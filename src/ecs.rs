@@ -0,0 +1,83 @@
+// Elastic Common Schema (ECS) serialization
+// https://www.elastic.co/guide/en/ecs/current/ecs-field-reference.html
+use crate::time::format_rfc3339;
+use crate::{LogEntry, LogQueue};
+use serde_json::{json, Map, Value};
+
+impl LogEntry {
+    /// Returns an Elastic Common Schema object for this entry.
+    /// `custom_fields` are included in `labels`.
+    pub fn to_ecs(&self) -> Value {
+        let mut log = Map::new();
+        log.insert(
+            "level".into(),
+            self.severity.to_string().to_lowercase().into(),
+        );
+        if let Some(class_name) = &self.class_name {
+            log.insert("logger".into(), class_name.clone().into());
+        }
+        let mut origin = Map::new();
+        if let Some(method_name) = &self.method_name {
+            origin.insert("function".into(), method_name.clone().into());
+        }
+        let mut file = Map::new();
+        if let Some(source_file) = &self.source_file {
+            file.insert("name".into(), source_file.clone().into());
+        }
+        if let Some(source_line) = self.source_line {
+            file.insert("line".into(), source_line.into());
+        }
+        if !file.is_empty() {
+            origin.insert("file".into(), Value::Object(file));
+        }
+        if !origin.is_empty() {
+            log.insert("origin".into(), Value::Object(origin));
+        }
+
+        let mut obj = Map::new();
+        obj.insert("@timestamp".into(), format_rfc3339(self.timestamp).into());
+        obj.insert("log".into(), Value::Object(log));
+        obj.insert("message".into(), self.text.clone().into());
+
+        if let Some(trace_id) = &self.trace_id {
+            obj.insert("trace".into(), json!({ "id": trace_id }));
+        }
+        if let Some(span_id) = &self.span_id {
+            obj.insert("span".into(), json!({ "id": span_id }));
+        }
+
+        let mut service = Map::new();
+        if let Some(environment) = &self.environment {
+            service.insert("environment".into(), environment.clone().into());
+        }
+        if let Some(app_version) = &self.app_version {
+            service.insert("version".into(), app_version.clone().into());
+        }
+        if !service.is_empty() {
+            obj.insert("service".into(), Value::Object(service));
+        }
+
+        let mut labels = Map::new();
+        if let Some(category) = &self.category {
+            labels.insert("category".into(), category.clone().into());
+        }
+        for (key, val) in self.custom_fields.iter() {
+            labels.insert(key.clone(), json!(val));
+        }
+        if !labels.is_empty() {
+            obj.insert("labels".into(), Value::Object(labels));
+        }
+        Value::Object(obj)
+    }
+}
+
+impl LogQueue {
+    /// Returns all queued entries as newline-delimited ECS json objects
+    pub fn to_ecs_json_lines(&self) -> String {
+        self.entries
+            .iter()
+            .map(|e| e.to_ecs().to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
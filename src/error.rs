@@ -0,0 +1,145 @@
+use std::fmt;
+
+/// Error returned by loggers
+#[derive(Debug)]
+pub enum ServiceLoggingError {
+    /// Request could not be sent, or the connection failed
+    NetworkError(String),
+    /// Service rejected credentials (http status 401 or 403)
+    AuthenticationError(String),
+    /// Request body exceeds the service limit, in bytes. Also returned for http status 413
+    PayloadTooLarge {
+        /// Request body size, or 0 if unknown
+        size: usize,
+        /// Maximum size accepted by the service, or 0 if unknown
+        limit: usize,
+        /// Id of the failed request, for loggers that number requests
+        request_id: Option<u64>,
+    },
+    /// Service returned a non-success status
+    BackendError {
+        /// Http status code
+        status: u16,
+        /// Response body, which may contain diagnostic info
+        body: String,
+        /// Id of the failed request, for loggers that number requests (e.g., [CoralogixLogger](crate::CoralogixLogger))
+        request_id: Option<u64>,
+    },
+    /// Entries could not be serialized
+    SerializationError(serde_json::Error),
+    /// Invalid logger configuration
+    ConfigError(String),
+    /// Other error from a logging backend
+    Other(String),
+    /// Error from a numbered request, for errors without a field or message for the request id
+    /// (e.g., SerializationError)
+    RequestFailed {
+        /// Id of the failed request
+        request_id: u64,
+        /// The error
+        source: Box<ServiceLoggingError>,
+    },
+}
+
+// prefix of messages for errors from numbered requests
+fn write_request_id(f: &mut fmt::Formatter<'_>, request_id: u64) -> fmt::Result {
+    write!(f, "Coralogix send failed [request_id={}]: ", request_id)
+}
+
+impl fmt::Display for ServiceLoggingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceLoggingError::NetworkError(msg) => write!(f, "Network Error: {}", msg),
+            ServiceLoggingError::AuthenticationError(msg) => {
+                write!(f, "Authentication Error: {}", msg)
+            }
+            ServiceLoggingError::PayloadTooLarge {
+                size,
+                limit,
+                request_id,
+            } => {
+                if let Some(id) = request_id {
+                    write_request_id(f, *id)?;
+                }
+                match (size, limit) {
+                    (0, _) => write!(f, "Payload Too Large"),
+                    (size, 0) => write!(f, "Payload Too Large: {} bytes", size),
+                    (size, limit) => write!(
+                        f,
+                        "Payload Too Large: {} bytes exceeds limit of {}",
+                        size, limit
+                    ),
+                }
+            }
+            ServiceLoggingError::BackendError {
+                status,
+                body,
+                request_id: None,
+            } => write!(f, "Logging Error: status:{} {}", status, body),
+            ServiceLoggingError::BackendError {
+                status,
+                body,
+                request_id: Some(id),
+            } => {
+                write_request_id(f, *id)?;
+                write!(f, "status {} {}", status, body)
+            }
+            ServiceLoggingError::SerializationError(e) => write!(f, "Serialization Error: {}", e),
+            ServiceLoggingError::ConfigError(msg) => write!(f, "Configuration Error: {}", msg),
+            ServiceLoggingError::Other(msg) => write!(f, "{}", msg),
+            ServiceLoggingError::RequestFailed { request_id, source } => {
+                write_request_id(f, *request_id)?;
+                write!(f, "{}", source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ServiceLoggingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ServiceLoggingError::SerializationError(e) => Some(e),
+            ServiceLoggingError::RequestFailed { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ServiceLoggingError {
+    fn from(e: reqwest::Error) -> Self {
+        ServiceLoggingError::NetworkError(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for ServiceLoggingError {
+    fn from(e: serde_json::Error) -> Self {
+        ServiceLoggingError::SerializationError(e)
+    }
+}
+
+impl From<crate::ConfigError> for ServiceLoggingError {
+    fn from(e: crate::ConfigError) -> Self {
+        ServiceLoggingError::ConfigError(e.to_string())
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl From<rmp_serde::encode::Error> for ServiceLoggingError {
+    fn from(e: rmp_serde::encode::Error) -> Self {
+        ServiceLoggingError::Other(format!("MessagePack Error: {}", e))
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl From<rmp_serde::decode::Error> for ServiceLoggingError {
+    fn from(e: rmp_serde::decode::Error) -> Self {
+        ServiceLoggingError::Other(format!("MessagePack Error: {}", e))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for ServiceLoggingError {
+    fn from(e: rusqlite::Error) -> Self {
+        ServiceLoggingError::Other(format!("SQLite Error: {}", e))
+    }
+}
@@ -0,0 +1,69 @@
+// LogQueue serialization to and from bytes in a selectable format
+use crate::{LogEntry, LogQueue, ServiceLoggingError};
+
+/// Output format for [LogQueue::serialize_to_bytes] and [LogQueue::from_bytes]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SerializationFormat {
+    /// One json object per line, as [to_json_lines](LogQueue::to_json_lines)
+    JsonLines,
+    /// A json array of entries
+    Json,
+    /// CSV with a header row, as [to_csv](LogQueue::to_csv). Export only
+    Csv {
+        /// LogEntry field names or keys in `custom_fields`
+        fields: Vec<String>,
+    },
+    /// A MessagePack array of entries, each encoded as [to_msgpack](LogEntry::to_msgpack)
+    #[cfg(feature = "msgpack")]
+    Msgpack,
+    /// One GELF 1.1 object per line, as [to_gelf_lines](LogQueue::to_gelf_lines). Export only
+    Gelf {
+        /// Value of the GELF `host` field. If empty, `-` is used
+        host: String,
+    },
+}
+
+impl LogQueue {
+    /// Returns all queued entries serialized in the format
+    pub fn serialize_to_bytes(
+        &self,
+        format: SerializationFormat,
+    ) -> Result<Vec<u8>, ServiceLoggingError> {
+        Ok(match format {
+            SerializationFormat::JsonLines => self.to_json_lines()?.into_bytes(),
+            SerializationFormat::Json => serde_json::to_vec(&self.entries)?,
+            SerializationFormat::Csv { fields } => {
+                let fields: Vec<&str> = fields.iter().map(String::as_str).collect();
+                self.to_csv(&fields).into_bytes()
+            }
+            #[cfg(feature = "msgpack")]
+            SerializationFormat::Msgpack => rmp_serde::to_vec_named(&self.entries)?,
+            SerializationFormat::Gelf { host } => {
+                let host = if host.is_empty() { "-" } else { &host };
+                self.to_gelf_lines(host).into_bytes()
+            }
+        })
+    }
+
+    /// Constructs a queue from bytes produced by [serialize_to_bytes](LogQueue::serialize_to_bytes).
+    /// Returns an error for the export-only formats, `Csv` and `Gelf`
+    pub fn from_bytes(
+        bytes: &[u8],
+        format: SerializationFormat,
+    ) -> Result<LogQueue, ServiceLoggingError> {
+        match format {
+            SerializationFormat::JsonLines => Ok(LogQueue::from_reader(bytes)?),
+            SerializationFormat::Json => Ok(LogQueue::from(
+                serde_json::from_slice::<Vec<LogEntry>>(bytes)?,
+            )),
+            #[cfg(feature = "msgpack")]
+            SerializationFormat::Msgpack => Ok(LogQueue::from(rmp_serde::from_slice::<
+                Vec<LogEntry>,
+            >(bytes)?)),
+            format => Err(ServiceLoggingError::Other(format!(
+                "Can't deserialize log entries from {:?}",
+                format
+            ))),
+        }
+    }
+}
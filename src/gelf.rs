@@ -0,0 +1,73 @@
+// GELF (Graylog Extended Log Format) serialization
+// https://go2docs.graylog.org/current/getting_in_log_data/gelf.html
+use crate::{LogEntry, LogQueue};
+use serde_json::{Map, Value};
+
+// GELF additional field names must match ^[\w\.\-]*$
+fn gelf_field_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("_{}", name)
+}
+
+impl LogEntry {
+    /// Returns a GELF 1.1 object for this entry. Optional fields and `custom_fields`
+    /// are included as additional fields, with names prefixed by '_'.
+    pub fn to_gelf(&self, host: &str) -> Value {
+        let mut obj = Map::new();
+        obj.insert("version".into(), "1.1".into());
+        obj.insert("host".into(), host.into());
+        obj.insert("short_message".into(), self.text.clone().into());
+        obj.insert("timestamp".into(), (self.timestamp as f64 / 1000.0).into());
+        obj.insert("level".into(), self.severity.syslog_level().into());
+
+        let mut extra = |key: &str, val: Value| {
+            let name = gelf_field_name(key);
+            // "_id" is reserved
+            if name != "_id" {
+                obj.insert(name, val);
+            }
+        };
+        for (key, val) in [
+            ("category", &self.category),
+            ("class_name", &self.class_name),
+            ("method_name", &self.method_name),
+            ("thread_id", &self.thread_id),
+            ("file", &self.source_file),
+            ("module", &self.source_module),
+        ] {
+            if let Some(val) = val {
+                extra(key, val.clone().into());
+            }
+        }
+        if let Some(line) = self.source_line {
+            extra("line", line.into());
+        }
+        for (key, val) in self.custom_fields.iter() {
+            extra(key, val.clone().into());
+        }
+        for (key, val) in self.metrics.iter() {
+            extra(key, (*val).into());
+        }
+        Value::Object(obj)
+    }
+}
+
+impl LogQueue {
+    /// Returns all queued entries as newline-delimited GELF objects
+    pub fn to_gelf_lines(&self, host: &str) -> String {
+        self.entries
+            .iter()
+            .map(|e| e.to_gelf(host).to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,29 @@
+// host name of the local system (not available for wasm32)
+use std::sync::OnceLock;
+
+/// Returns the name of the local host, or None if it can't be determined.
+/// The name is read once, from `/proc/sys/kernel/hostname` (linux), the `COMPUTERNAME`
+/// environment variable (windows), or the output of the `hostname` command.
+pub(crate) fn hostname() -> Option<&'static str> {
+    static HOSTNAME: OnceLock<Option<String>> = OnceLock::new();
+    HOSTNAME.get_or_init(read_hostname).as_deref()
+}
+
+fn read_hostname() -> Option<String> {
+    let name = std::fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .or_else(|| {
+            std::process::Command::new("hostname")
+                .output()
+                .ok()
+                .filter(|out| out.status.success())
+                .and_then(|out| String::from_utf8(out.stdout).ok())
+        })?;
+    let name = name.trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
@@ -0,0 +1,165 @@
+// json and json-lines (NDJSON) serialization
+use crate::{LogEntry, LogQueue, Severity};
+use serde_json::{json, Map, Value};
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+// converts a rust field name (snake_case) to its serialized name (camelCase)
+fn camel_case(name: &str) -> String {
+    let mut parts = name.split('_');
+    let mut buf = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(c) = chars.next() {
+            buf.extend(c.to_uppercase());
+            buf.push_str(chars.as_str());
+        }
+    }
+    buf
+}
+
+impl LogEntry {
+    /// Returns this entry serialized as json, with the same field names used for Coralogix
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Returns this entry as a json object, with the same field names as [to_json](LogEntry::to_json),
+    /// except that `custom_fields` are inline, at the top level. Standard fields take precedence
+    /// over custom fields with the same name. Fields that are not set are omitted
+    pub fn to_serde_value(&self) -> Value {
+        let mut obj = match serde_json::to_value(self) {
+            Ok(Value::Object(map)) => map,
+            _ => Map::new(),
+        };
+        obj.remove("customFields");
+        for (key, val) in self.custom_fields.iter() {
+            if !obj.contains_key(key) {
+                obj.insert(key.clone(), val.clone().into());
+            }
+        }
+        Value::Object(obj)
+    }
+
+    /// Returns the value of a field of [to_serde_value](LogEntry::to_serde_value), by name.
+    /// Standard fields can be named with their json (`className`) or rust (`class_name`) names.
+    /// Returns None if the field is not set
+    pub fn field_value(&self, name: &str) -> Option<Value> {
+        let mut obj = match self.to_serde_value() {
+            Value::Object(map) => map,
+            _ => return None,
+        };
+        obj.remove(name).or_else(|| obj.remove(&camel_case(name)))
+    }
+}
+
+impl LogQueue {
+    /// Returns all queued entries as json lines (NDJSON): one json object per line
+    pub fn to_json_lines(&self) -> Result<String, serde_json::Error> {
+        let mut buf = String::new();
+        for entry in self.entries.iter() {
+            if !buf.is_empty() {
+                buf.push('\n');
+            }
+            buf.push_str(&entry.to_json()?);
+        }
+        Ok(buf)
+    }
+
+    /// Writes all queued entries to `writer` as json lines, one entry at a time, then flushes.
+    /// The output is the same as [to_json_lines](LogQueue::to_json_lines)
+    pub fn write_to_writer(&self, mut writer: impl Write) -> Result<(), std::io::Error> {
+        for (n, entry) in self.entries.iter().enumerate() {
+            if n > 0 {
+                writer.write_all(b"\n")?;
+            }
+            serde_json::to_writer(&mut writer, entry)?;
+        }
+        writer.flush()
+    }
+
+    /// Writes all queued entries to a file as json lines, replacing the file if it exists.
+    /// See [write_to_writer](LogQueue::write_to_writer)
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        let file = std::fs::File::create(path)?;
+        self.write_to_writer(std::io::BufWriter::new(file))
+    }
+
+    /// Returns a single entry with the given severity, summarizing the queue, e.g., to replace
+    /// a burst of debug entries. Its `text` is a json object with `total_entries`, `by_severity`
+    /// (the number of entries at each severity), `oldest_timestamp`, `newest_timestamp`,
+    /// and `highest_severity_text`, the text of the first entry with the highest severity.
+    /// For an empty queue, the timestamps and text are null
+    pub fn to_summary_entry(&self, severity: Severity) -> LogEntry {
+        let mut by_severity = Map::new();
+        for sev in Severity::iter() {
+            let count = self.entries.iter().filter(|e| e.severity == sev).count();
+            by_severity.insert(sev.to_string().to_lowercase(), count.into());
+        }
+        // max_by_key returns the last maximum
+        let highest = self
+            .entries
+            .iter()
+            .rev()
+            .max_by_key(|e| &e.severity)
+            .map(|e| e.text.as_str());
+        let summary = json!({
+            "total_entries": self.entries.len(),
+            "by_severity": by_severity,
+            "oldest_timestamp": self.entries.iter().map(|e| e.timestamp).min(),
+            "newest_timestamp": self.entries.iter().map(|e| e.timestamp).max(),
+            "highest_severity_text": highest,
+        });
+        LogEntry {
+            severity,
+            text: summary.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Constructs a queue from json lines, as produced by [to_json_lines](LogQueue::to_json_lines).
+    /// Blank lines are ignored. If any lines can't be parsed, returns the errors
+    /// for all of them, with 1-based line numbers.
+    pub fn from_json_lines(s: &str) -> Result<LogQueue, Vec<(usize, serde_json::Error)>> {
+        let mut entries = Vec::new();
+        let mut errors = Vec::new();
+        for (n, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => errors.push((n + 1, e)),
+            }
+        }
+        if errors.is_empty() {
+            Ok(LogQueue::from(entries))
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Constructs a queue from json lines read from `reader`, one line at a time, so the
+    /// input doesn't need to fit in memory as a string. Blank lines are ignored.
+    /// Returns the first read or parse error.
+    pub fn from_reader(mut reader: impl BufRead) -> Result<Self, serde_json::Error> {
+        let mut entries = Vec::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).map_err(serde_json::Error::io)? == 0 {
+                break;
+            }
+            if !line.trim().is_empty() {
+                entries.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(LogQueue::from(entries))
+    }
+
+    /// Constructs a queue from a json lines file. See [from_reader](LogQueue::from_reader)
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let file = std::fs::File::open(path)?;
+        Ok(Self::from_reader(std::io::BufReader::new(file))?)
+    }
+}
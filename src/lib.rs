@@ -1,23 +1,199 @@
 #![deny(missing_docs)]
+#![cfg_attr(nostd, no_std)]
 //! Library for aggregating logs and sending to logging service.
 //! Contains implementations for [Coralogix](https://coralogix.com/)
 //! and (for wasm) console.log
+//!
+//! With the `no-std` feature (and without `std`), only [LogEntry], [LogQueue], [Severity],
+//! [LogLevel], and the logging macros are available. Entries are not timestamped
+//! automatically; set `timestamp` from the target's clock, e.g., with the `timestamp` key of [`log!`].
+extern crate alloc;
+
+#[cfg(all(
+    nostd,
+    any(
+        feature = "async-queue",
+        feature = "azure",
+        feature = "cloudflare-workers",
+        feature = "fluentd",
+        feature = "honeycomb",
+        feature = "log",
+        feature = "mezmo",
+        feature = "msgpack",
+        feature = "newrelic",
+        feature = "otel",
+        feature = "papertrail",
+        feature = "sentry",
+        feature = "slog",
+        feature = "sqlite",
+        feature = "tracing",
+        feature = "webhook"
+    )
+))]
+compile_error!("feature \"no-std\" can't be used with loggers or other features that require std");
+
+#[cfg(feature = "async-queue")]
+mod async_queue;
+mod backends;
+#[cfg(not(nostd))]
+mod batch_logger;
+#[cfg(not(nostd))]
+mod config;
+#[cfg(not(nostd))]
+mod contextual_logger;
+#[cfg(not(nostd))]
+mod csv;
+#[cfg(not(nostd))]
+mod defaults;
+#[cfg(not(nostd))]
+mod ecs;
+#[cfg(not(nostd))]
+mod error;
+#[cfg(not(nostd))]
+mod export;
+#[cfg(not(nostd))]
+mod gelf;
+#[cfg(all(not(nostd), not(target_arch = "wasm32")))]
+mod hostname;
+#[cfg(not(nostd))]
+mod json;
+#[cfg(not(nostd))]
+pub mod log_context;
+#[cfg(feature = "log")]
+mod log_record;
 mod logging;
+#[cfg(not(nostd))]
+mod middleware;
+#[cfg(not(nostd))]
+mod mock_logger;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(not(nostd))]
+mod panic_hook;
+#[cfg(not(nostd))]
+mod registry;
+#[cfg(not(nostd))]
+mod serializer;
+#[cfg(not(nostd))]
+mod shared_queue;
+#[cfg(not(nostd))]
+mod shutdown_guard;
+#[cfg(feature = "slog")]
+mod slog_drain;
+#[cfg(not(nostd))]
+mod stats;
+#[cfg(not(nostd))]
+mod syslog;
 mod time;
+#[cfg(feature = "tracing")]
+mod tracing_event;
+#[cfg(not(nostd))]
+mod vec_logger;
+#[cfg(all(feature = "cloudflare-workers", target_arch = "wasm32"))]
+mod worker_logger;
 
+#[cfg(feature = "async-queue")]
+pub use async_queue::{AsyncLogQueue, AsyncLogQueueReceiver, AsyncLogQueueSender};
+#[cfg(feature = "azure")]
+pub use backends::azure::{AzureMonitorConfig, AzureMonitorLogger};
+#[cfg(feature = "otel")]
+pub use backends::coralogix_otlp::{CoralogixOtlpConfig, CoralogixOtlpLogger};
+#[cfg(feature = "fluentd")]
+pub use backends::fluentd::{FluentdConfig, FluentdLogger};
+#[cfg(feature = "honeycomb")]
+pub use backends::honeycomb::{HoneycombConfig, HoneycombLogger};
+#[cfg(feature = "mezmo")]
+pub use backends::mezmo::{MezmoConfig, MezmoLogger};
+#[cfg(feature = "newrelic")]
+pub use backends::newrelic::{NewRelicConfig, NewRelicLogger};
+#[cfg(feature = "otel")]
+pub use backends::otel::{OtelConfig, OtelLogger};
+#[cfg(feature = "papertrail")]
+pub use backends::papertrail::{PapertrailConfig, PapertrailLogger};
+#[cfg(feature = "sentry")]
+pub use backends::sentry::{SentryConfig, SentryLogger};
+#[cfg(feature = "sqlite")]
+pub use backends::sqlite::{SqliteConfig, SqliteLogger};
+#[cfg(feature = "webhook")]
+pub use backends::webhook::{WebhookBodyFormat, WebhookConfig, WebhookLogger};
+#[cfg(not(nostd))]
+pub use batch_logger::BatchLogger;
+#[cfg(not(nostd))]
+pub use config::{ConfigError, CoralogixConfigOwned, CoralogixRegion};
+#[cfg(not(nostd))]
+pub use contextual_logger::ContextualLogger;
+#[cfg(not(nostd))]
+pub use defaults::{LogEntryDefaults, LogQueueWithDefaults};
+#[cfg(not(nostd))]
+pub use error::ServiceLoggingError;
+#[cfg(not(nostd))]
+pub use export::SerializationFormat;
 /// ConsoleLogger sends output to the javascript console (wasm32 targets) or stdout (println! for
 /// non-wasm32 targets)
+#[cfg(not(nostd))]
 pub use logging::ConsoleLogger;
 pub use logging::{
-    silent_logger, CoralogixConfig, CoralogixLogger, LogEntry, LogLevel, LogQueue, Logger, Severity,
+    get_min_severity, is_enabled, set_min_severity, LogEntry, LogLevel, LogQueue, Severity,
+    METRIC_KEYS,
+};
+#[cfg(not(nostd))]
+pub use logging::{
+    silent_logger, CloneableLogger, CoralogixConfig, CoralogixLogger, Logger, NamedLogger,
+    NullLogger,
 };
+#[cfg(not(nostd))]
+pub use logging::{ConsoleLoggerConfig, LogFormat, TimestampFormat};
+#[cfg(not(nostd))]
+pub use middleware::{
+    EntryDeduplicator, FieldInjector, FieldRedactor, LoggerMiddleware, MiddlewareLogger,
+    SeverityFilter,
+};
+#[cfg(not(nostd))]
+pub use mock_logger::MockLogger;
+#[cfg(not(nostd))]
+pub use panic_hook::install_panic_hook;
+#[cfg(not(nostd))]
+pub use registry::{LoggerRegistry, LOGGER_REGISTRY};
+#[cfg(not(nostd))]
+pub use serializer::{CoralogixSerializer, EcsSerializer, GelfSerializer, LogEntrySerializer};
+#[cfg(not(nostd))]
+pub use shared_queue::SharedLogQueue;
+#[cfg(not(nostd))]
+pub use shutdown_guard::LoggerShutdownGuard;
+#[cfg(feature = "slog")]
+pub use slog_drain::ServiceLoggingDrain;
+#[cfg(not(nostd))]
+pub use stats::{LoggerStats, LoggerStatsHandle};
+#[cfg(not(nostd))]
+pub use time::{current_time_micros, current_time_millis};
+#[cfg(not(nostd))]
+pub use vec_logger::{VecLogger, VecLoggerHandle};
+#[cfg(all(feature = "cloudflare-workers", target_arch = "wasm32"))]
+pub use worker_logger::WorkerLogger;
+
+// Used by the logging macros, so they expand the same way with and without std
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::collections::BTreeMap;
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use serde_json;
+
+    /// Adds the thread's log context to `fields`. No-op for no_std, which has no thread context
+    #[allow(unused_variables)]
+    pub fn merge_context(fields: &mut BTreeMap<String, String>) {
+        #[cfg(not(nostd))]
+        crate::log_context::merge_into(fields);
+    }
+}
 
 /// The `log!` macro can be used to create structured log entries for later use by [Logger.send](Logger::send)
 /// The first two parameters are fixed:
 ///  - a writable queue (or something with a log() method)
 ///  - severity level
+///
 /// All remaining parameters are in the form key:value. Key is any word (using the same syntax
-/// as
+/// as a rust identifier).
 ///
 /// ```
 /// use service_logging::{log, LogQueue, Severity::Info};
@@ -32,37 +208,167 @@ pub use logging::{
 /// (such as [LogQueue] or [Context](https://docs.rs/wasm-service/0.2/wasm_service/struct.Context.html))
 ///
 /// Values can be anything that implements [ToString]
-/// Key names must use the same syntax as a rust identifier, e.g., no spaces, punctuation, etc.
+/// Key names must use the same syntax as a rust identifier, e.g., no spaces, punctuation, etc.,
+/// or be a string literal, for names such as `"x-forwarded-for"`:
+///
+/// ```
+/// use service_logging::{log, LogQueue, Severity::Info};
+/// let mut lq = LogQueue::default();
+///
+/// log!(lq, Info, method: "GET", "content-type": "text/html");
+/// ```
 ///
 /// The following keys are "special" (known to Coralogix and used for categorization
-/// in the coralogix dashboard):  `text`, `category`, `class_name`, `method_name`, `thread_id`
+/// in the coralogix dashboard):  `text`, `category`, `class_name`, `method_name`, `thread_id`.
+/// The keys `trace_id` and `span_id` are also special, and set the entry's fields for tracing correlation.
+/// The key `timestamp`, with a value in milliseconds since epoch, overrides the current time.
 /// If `text` is not defined, all non-coralogix keys are converted into a json string and
-/// passed as the value of 'text'. (If `text` is also defined, any non-coralogix keys will be
-/// silently dropped).
+/// passed as the value of 'text'. (If `text` is also defined, any non-coralogix keys are
+/// stored in `custom_fields`).
+///
+/// Keys in [METRIC_KEYS] (e.g., `duration_ms`, `status_code`) with numeric values are stored
+/// in the entry's `metrics`, so they are serialized as json numbers.
+///
+/// The key `tags` is a comma-separated list of tags, e.g., `tags: "auth,login"`, which are
+/// added to the entry's `tags`.
+///
+/// Key-value pairs in the thread's [log_context] are added to every entry, unless
+/// the key is already set in the `log!` parameters.
+///
+/// When the `location` feature is enabled, entries also record the file, line, and module
+/// of the `log!` statement in `source_file`, `source_line`, and `source_module`.
+/// When the `micros` feature is enabled, entries also have a microsecond timestamp in `timestamp_us`.
+///
+/// If the severity is below the global minimum set with [set_min_severity], the entry
+/// is not created and none of the values are evaluated.
 #[macro_export]
 macro_rules! log {
     ( $queue:expr,  $sev:expr,  $( $key:tt $_t:tt  $val:expr ),* ) => {{
-        let mut fields: std::collections::BTreeMap<String, String> = std::collections::BTreeMap::new();
+        let sev: $crate::Severity = $sev;
+        if sev >= $crate::get_min_severity() {
+            let entry = $crate::make_log_entry!(sev, $( $key $_t $val ),*);
+            $queue.log(entry);
+        }
+    }};
+}
+
+/// The `make_log_entry!` macro has the same syntax as [`log!`], without the queue parameter,
+/// and returns the constructed [LogEntry] instead of adding it to a queue.
+///
+/// ```
+/// use service_logging::{make_log_entry, Severity};
+///
+/// let e = make_log_entry!(Severity::Info, method: "GET", status: 200);
+/// assert_eq!(e.severity, Severity::Info);
+/// assert_eq!(e.text, r#"{"method":"GET","status":"200"}"#);
+/// ```
+#[macro_export]
+macro_rules! make_log_entry {
+    ( $sev:expr,  $( $key:tt $_t:tt  $val:expr ),* ) => {{
+        use $crate::__private::{BTreeMap, String, ToString};
+        let mut fields: BTreeMap<String, String> = BTreeMap::new();
         let mut has_text = false;
-        let mut entry = service_logging::LogEntry { severity: ($sev), ..Default::default() };
+        let mut entry = $crate::LogEntry { severity: ($sev), ..::core::default::Default::default() };
+        entry.set_location(file!(), line!(), module_path!());
+        entry.set_timestamp_micros();
         $(
             let val = $val.to_string();
             let key = stringify!($key);
+            // string literal keys are stringified with their quotes
+            let key = key.strip_prefix('"').and_then(|k| k.strip_suffix('"')).unwrap_or(key);
             match key {
                 "text" => { entry.text = val; has_text = true; },
                 "category" => { entry.category = Some(val); },
                 "class_name" => { entry.class_name = Some(val); },
                 "method_name" => { entry.method_name = Some(val); },
                 "thread_id" => { entry.thread_id = Some(val); },
+                "trace_id" => { entry.trace_id = Some(val); },
+                "span_id" => { entry.span_id = Some(val); },
+                "timestamp" => match val.parse::<u64>() {
+                    Ok(ts) => { entry.set_timestamp(ts); },
+                    _ => { fields.insert(key.to_string(), val); },
+                },
+                k if $crate::METRIC_KEYS.contains(&k) => match val.parse::<f64>() {
+                    Ok(n) if n.is_finite() => { entry.metrics.insert(k.to_string(), n); },
+                    _ => { fields.insert(k.to_string(), val); },
+                },
+                "tags" => {
+                    entry.tags.extend(val.split(',').map(|t| t.trim()).filter(|t| !t.is_empty()).map(String::from));
+                },
                 _ => { fields.insert(key.to_string(), val); }
             }
         )*
-        if !has_text {
-            entry.text = match serde_json::to_string(&fields) {
+        $crate::__private::merge_context(&mut fields);
+        if has_text {
+            entry.custom_fields = fields;
+        } else {
+            entry.text = match $crate::__private::serde_json::to_string(&fields) {
                 Ok(s) => s,
-                Err(e) => format!("error serializing message: {}",e),
+                Err(e) => $crate::__private::format!("error serializing message: {}",e),
             };
         }
-        $queue.log(entry);
+        entry
+    }};
+}
+
+/// The `log_if!` macro logs only if a condition is true. The condition is the first
+/// parameter; the remaining parameters are the same as for [`log!`].
+/// When the condition is false, no values are evaluated and no entry is created.
+///
+/// ```
+/// use service_logging::{log_if, LogQueue, Severity::Debug};
+/// let mut lq = LogQueue::default();
+///
+/// log_if!(cfg!(debug_assertions), lq, Debug, method: "GET", status: 200);
+/// ```
+#[macro_export]
+macro_rules! log_if {
+    ( $cond:expr, $queue:expr,  $sev:expr,  $( $key:tt $_t:tt  $val:expr ),* ) => {{
+        if $cond {
+            $crate::log!($queue, $sev, $( $key $_t $val ),*);
+        }
+    }};
+}
+
+/// The `log_if_severity!` macro logs only if the entry severity is at or above a minimum
+/// severity. The minimum is the first parameter; the remaining parameters are the same as for [`log!`].
+/// If the severity is below the minimum, the macro returns before any values are
+/// evaluated or formatted, so disabled log statements are inexpensive.
+///
+/// ```
+/// use service_logging::{log_if_severity, LogQueue, Severity};
+/// let mut lq = LogQueue::default();
+/// let min = Severity::Info;
+///
+/// // not logged: Debug is below Info
+/// log_if_severity!(min.clone(), lq, Severity::Debug, method: "GET", status: 200);
+/// assert!(lq.is_empty());
+/// ```
+#[macro_export]
+macro_rules! log_if_severity {
+    ( $min:expr, $queue:expr,  $sev:expr,  $( $key:tt $_t:tt  $val:expr ),* ) => {{
+        let sev: $crate::Severity = $sev;
+        if sev >= $min {
+            $crate::log!($queue, sev, $( $key $_t $val ),*);
+        }
+    }};
+}
+
+/// The `logf!` macro creates a log entry whose `text` is built with format-string syntax,
+/// as in [`format!`]. The format string and its arguments may be followed by a semicolon and
+/// key:value pairs for the special fields (`category`, `class_name`, `method_name`, `thread_id`).
+///
+/// ```
+/// use service_logging::{logf, LogQueue, Severity::Info};
+/// let mut lq = LogQueue::default();
+/// let user_id = 42;
+///
+/// logf!(lq, Info, "user {} logged in", user_id);
+/// logf!(lq, Info, "user {} logged out", user_id; category: "auth");
+/// ```
+#[macro_export]
+macro_rules! logf {
+    ( $queue:expr,  $sev:expr,  $fmt:literal $(, $arg:expr)* $(; $( $key:tt $_t:tt  $val:expr ),* )? ) => {{
+        $crate::log!($queue, $sev, text: $crate::__private::format!($fmt $(, $arg)*) $( $(, $key $_t $val)* )?);
     }};
 }
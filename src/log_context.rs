@@ -0,0 +1,72 @@
+//! Thread-local key-value pairs that are attached to every entry created with [`log!`](crate::log)
+//! on the current thread.
+//!
+//! ```
+//! use service_logging::{log, log_context, LogQueue, Severity::Info};
+//! let mut lq = LogQueue::default();
+//!
+//! log_context::with_context(vec![("request_id", "r-1")], || {
+//!     log!(lq, Info, method: "GET");
+//! });
+//! assert_eq!(lq.take()[0].text, r#"{"method":"GET","request_id":"r-1"}"#);
+//! ```
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+thread_local! {
+    static CONTEXT: RefCell<BTreeMap<String, String>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// Adds a key-value pair to the current thread's log context, replacing any previous value for the key
+pub fn set(key: &str, value: impl ToString) {
+    CONTEXT.with(|c| {
+        c.borrow_mut().insert(key.to_string(), value.to_string());
+    });
+}
+
+/// Removes all key-value pairs from the current thread's log context
+pub fn clear() {
+    CONTEXT.with(|c| c.borrow_mut().clear());
+}
+
+/// Runs `f` with the key-value pairs added to the log context.
+/// When `f` returns (or panics), the context is restored to its previous state.
+pub fn with_context<K, V, R>(pairs: impl IntoIterator<Item = (K, V)>, f: impl FnOnce() -> R) -> R
+where
+    K: Into<String>,
+    V: ToString,
+{
+    // restores saved context on drop
+    struct Restore(Option<BTreeMap<String, String>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            if let Some(saved) = self.0.take() {
+                CONTEXT.with(|c| *c.borrow_mut() = saved);
+            }
+        }
+    }
+
+    let saved = CONTEXT.with(|c| {
+        let mut ctx = c.borrow_mut();
+        let saved = ctx.clone();
+        for (k, v) in pairs {
+            ctx.insert(k.into(), v.to_string());
+        }
+        saved
+    });
+    let _restore = Restore(Some(saved));
+    f()
+}
+
+/// Adds context values to `fields`. Fields already present are not overwritten.
+/// Called by the log! macro.
+#[doc(hidden)]
+pub fn merge_into(fields: &mut BTreeMap<String, String>) {
+    CONTEXT.with(|c| {
+        for (k, v) in c.borrow().iter() {
+            if !fields.contains_key(k) {
+                fields.insert(k.clone(), v.clone());
+            }
+        }
+    });
+}
@@ -0,0 +1,38 @@
+// log crate integration (feature "log")
+use crate::{LogEntry, Severity};
+
+impl Severity {
+    /// Returns the severity for a `log` crate level. `log::Level::Trace` is `Debug`,
+    /// the most verbose severity, and `log::Level::Debug` is `Verbose`
+    pub fn from_log_level(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => Severity::Error,
+            log::Level::Warn => Severity::Warning,
+            log::Level::Info => Severity::Info,
+            log::Level::Debug => Severity::Verbose,
+            log::Level::Trace => Severity::Debug,
+        }
+    }
+}
+
+impl From<log::Level> for Severity {
+    fn from(level: log::Level) -> Self {
+        Severity::from_log_level(level)
+    }
+}
+
+/// The record message is the entry's `text`, the target is its `class_name`, and the
+/// module path is its `method_name`. The file and line are recorded in `source_file` and `source_line`
+impl From<&log::Record<'_>> for LogEntry {
+    fn from(record: &log::Record<'_>) -> Self {
+        LogEntry {
+            severity: Severity::from_log_level(record.level()),
+            text: record.args().to_string(),
+            class_name: Some(record.target().to_string()),
+            method_name: record.module_path().map(String::from),
+            source_file: record.file().map(String::from),
+            source_line: record.line(),
+            ..Default::default()
+        }
+    }
+}
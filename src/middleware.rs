@@ -0,0 +1,164 @@
+use crate::{LogEntry, Logger, ServiceLoggingError, Severity};
+use async_trait::async_trait;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+/// Transformation of a batch of entries, applied by a [MiddlewareLogger] before
+/// entries are sent. Middleware may modify, remove, or add entries.
+pub trait LoggerMiddleware: Send {
+    /// Returns the entries to send
+    fn process(&self, entries: Vec<LogEntry>) -> Vec<LogEntry>;
+}
+
+/// Logger that passes entries through a chain of [LoggerMiddleware], in order,
+/// and sends the result to an inner logger. Batches that are empty after processing are not sent.
+///
+/// ```
+/// # async fn run() {
+/// use service_logging::{
+///     log, FieldInjector, LogQueue, Logger, LoggerMiddleware, MiddlewareLogger, Severity,
+///     SeverityFilter, VecLogger,
+/// };
+/// let (inner, handle) = VecLogger::new();
+/// let middlewares: Vec<Box<dyn LoggerMiddleware>> = vec![
+///     Box::new(SeverityFilter::new(Severity::Info)),
+///     Box::new(FieldInjector::new([("region", "us-east-1")])),
+/// ];
+/// let logger = MiddlewareLogger::new(inner, middlewares);
+///
+/// let mut lq = LogQueue::default();
+/// log!(lq, Severity::Debug, text: "dropped");
+/// log!(lq, Severity::Info, text: "sent");
+/// logger.send("sub", lq.take()).await.unwrap();
+/// assert_eq!(handle.entries().len(), 1);
+/// assert!(handle.has_entry_matching(|e| e.custom_fields["region"] == "us-east-1"));
+/// # }
+/// ```
+pub struct MiddlewareLogger {
+    inner: Box<dyn Logger + Send>,
+    middlewares: Vec<Box<dyn LoggerMiddleware>>,
+}
+
+impl MiddlewareLogger {
+    /// Constructs logger that applies `middlewares`, in order, then sends to `inner`
+    pub fn new(inner: Box<dyn Logger + Send>, middlewares: Vec<Box<dyn LoggerMiddleware>>) -> Self {
+        Self { inner, middlewares }
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for MiddlewareLogger {
+    /// Processes entries with each middleware, then sends the result to the inner logger
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        let entries = self
+            .middlewares
+            .iter()
+            .fold(entries, |entries, m| m.process(entries));
+        if entries.is_empty() {
+            return Ok(());
+        }
+        self.inner.send(sub, entries).await
+    }
+
+    async fn health_check(&self) -> Result<Duration, ServiceLoggingError> {
+        self.inner.health_check().await
+    }
+
+    async fn shutdown(&self) -> Result<(), ServiceLoggingError> {
+        self.inner.shutdown().await
+    }
+}
+
+/// Middleware that removes entries below a minimum severity
+#[derive(Clone, Debug)]
+pub struct SeverityFilter {
+    min: Severity,
+}
+
+impl SeverityFilter {
+    /// Constructs filter that keeps entries with severity `min` or higher
+    pub fn new(min: Severity) -> Self {
+        Self { min }
+    }
+}
+
+impl LoggerMiddleware for SeverityFilter {
+    fn process(&self, mut entries: Vec<LogEntry>) -> Vec<LogEntry> {
+        entries.retain(|e| e.severity >= self.min);
+        entries
+    }
+}
+
+/// Middleware that redacts fields of every entry. See [redact_field](LogEntry::redact_field)
+#[derive(Clone, Debug)]
+pub struct FieldRedactor {
+    fields: Vec<String>,
+}
+
+impl FieldRedactor {
+    /// Constructs redactor for the named fields
+    pub fn new<S: Into<String>>(fields: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            fields: fields.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl LoggerMiddleware for FieldRedactor {
+    fn process(&self, mut entries: Vec<LogEntry>) -> Vec<LogEntry> {
+        for entry in entries.iter_mut() {
+            for field in self.fields.iter() {
+                entry.redact_field(field);
+            }
+        }
+        entries
+    }
+}
+
+/// Middleware that removes entries with the same severity, text, and custom fields
+/// as an earlier entry in the same batch
+#[derive(Clone, Debug, Default)]
+pub struct EntryDeduplicator {}
+
+impl EntryDeduplicator {
+    /// Constructs deduplicator
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LoggerMiddleware for EntryDeduplicator {
+    fn process(&self, mut entries: Vec<LogEntry>) -> Vec<LogEntry> {
+        let mut seen = BTreeSet::new();
+        entries
+            .retain(|e| seen.insert((e.severity.clone(), e.text.clone(), e.custom_fields.clone())));
+        entries
+    }
+}
+
+/// Middleware that adds custom fields to every entry. Fields already set in an entry are not changed
+#[derive(Clone, Debug, Default)]
+pub struct FieldInjector {
+    fields: BTreeMap<String, String>,
+}
+
+impl FieldInjector {
+    /// Constructs injector for the key-value pairs
+    pub fn new<K: Into<String>, V: Into<String>>(fields: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self {
+            fields: fields
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+impl LoggerMiddleware for FieldInjector {
+    fn process(&self, mut entries: Vec<LogEntry>) -> Vec<LogEntry> {
+        for entry in entries.iter_mut() {
+            entry.add_missing_fields(&self.fields);
+        }
+        entries
+    }
+}
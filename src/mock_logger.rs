@@ -0,0 +1,69 @@
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Logger for testing error handling. Stores entries in memory, and can be scheduled
+/// to fail the next `n` calls to `send`, as if the logging service returned status 503.
+/// Clones share the same failure schedule and captured entries, so a clone can be kept
+/// for inspection after the logger is boxed.
+///
+/// ```
+/// # async fn run() {
+/// use service_logging::{LogEntry, Logger, MockLogger};
+/// let mock = MockLogger::new();
+/// let logger: Box<dyn Logger + Send> = Box::new(mock.clone());
+/// mock.fail_next(1);
+/// assert!(logger.send("sub", vec![LogEntry::default()]).await.is_err());
+/// assert!(logger.send("sub", vec![LogEntry::default()]).await.is_ok());
+/// assert_eq!(mock.captured_entries().len(), 1);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockLogger {
+    fail_first_n: Arc<AtomicUsize>,
+    captured: Arc<Mutex<Vec<LogEntry>>>,
+}
+
+impl MockLogger {
+    /// Constructs a logger that succeeds until told to fail
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedules the next `n` calls to `send` to fail. Replaces any previously scheduled failures
+    pub fn fail_next(&self, n: usize) {
+        self.fail_first_n.store(n, Ordering::SeqCst);
+    }
+
+    /// Returns a copy of all entries received by successful sends
+    pub fn captured_entries(&self) -> Vec<LogEntry> {
+        self.captured
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for MockLogger {
+    /// Stores entries in memory, or returns an error if a failure is scheduled
+    async fn send(&self, _sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        let failing = self
+            .fail_first_n
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if failing {
+            return Err(ServiceLoggingError::BackendError {
+                status: 503,
+                body: "Service Unavailable (MockLogger)".to_string(),
+                request_id: None,
+            });
+        }
+        self.captured
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .extend(entries);
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+// MessagePack serialization (feature "msgpack")
+use crate::LogEntry;
+
+impl LogEntry {
+    /// Returns this entry serialized as MessagePack. Fields are encoded as a map,
+    /// with the same names used for json
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    /// Constructs an entry from MessagePack bytes produced by [to_msgpack](LogEntry::to_msgpack)
+    pub fn from_msgpack(bytes: &[u8]) -> Result<LogEntry, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+}
@@ -0,0 +1,84 @@
+// panic hook that logs panics
+use crate::time::current_time_millis;
+use crate::{LogEntry, LogQueue, Severity};
+use std::cell::Cell;
+use std::sync::{Arc, Mutex, TryLockError};
+use std::time::{Duration, Instant};
+
+// how long to wait for a queue lock held by another thread
+const LOCK_TIMEOUT: Duration = Duration::from_millis(100);
+
+thread_local! {
+    // set while this thread runs the hook, so a panic inside the hook isn't queued
+    static IN_HOOK: Cell<bool> = const { Cell::new(false) };
+}
+
+// Adds the entry to the queue. The hook runs before unwinding, so the panicking thread may
+// already hold the lock (e.g., while using the queue from SharedLogQueue::inner), and blocking
+// would deadlock. Instead, retries for up to LOCK_TIMEOUT, which is enough for another thread
+// to release it, then gives up and returns the unqueued entry. A lock poisoned by an earlier panic is still usable.
+fn queue_entry(queue: &Mutex<LogQueue>, entry: LogEntry) -> Option<LogEntry> {
+    let deadline = Instant::now() + LOCK_TIMEOUT;
+    loop {
+        match queue.try_lock() {
+            Ok(mut queue) => {
+                queue.log(entry);
+                return None;
+            }
+            Err(TryLockError::Poisoned(e)) => {
+                e.into_inner().log(entry);
+                return None;
+            }
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(1))
+            }
+            Err(TryLockError::WouldBlock) => return Some(entry),
+        }
+    }
+}
+
+/// Installs a panic hook that adds a Critical entry to the queue for each panic,
+/// with the panic message and location. The previously installed hook
+/// (by default, the one that prints to stderr) is called afterwards.
+/// If another thread holds the queue lock, the hook waits briefly for it. If the lock
+/// isn't released (for example, because the panicking thread holds it), the entry is written
+/// to stderr instead of being queued.
+pub fn install_panic_hook(queue: Arc<Mutex<LogQueue>>) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let payload = info.payload();
+        let msg = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Box<dyn Any>");
+        let mut entry = LogEntry {
+            timestamp: current_time_millis(),
+            severity: Severity::Critical,
+            category: Some("panic".to_string()),
+            ..Default::default()
+        };
+        entry.text = match info.location() {
+            Some(location) => {
+                entry.source_file = Some(location.file().to_string());
+                entry.source_line = Some(location.line());
+                format!("panicked at {}: {}", location, msg)
+            }
+            None => format!("panicked: {}", msg),
+        };
+        if let Some(name) = std::thread::current().name() {
+            entry.thread_id = Some(name.to_string());
+        }
+        entry.set_timestamp_micros();
+        if !IN_HOOK.with(|h| h.replace(true)) {
+            if let Some(entry) = queue_entry(&queue, entry) {
+                eprintln!(
+                    "service-logging: log queue is locked, panic not queued: {}",
+                    entry
+                )
+            }
+            IN_HOOK.with(|h| h.set(false));
+        }
+        previous(info);
+    }));
+}
@@ -0,0 +1,66 @@
+// named loggers
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Loggers, by name
+#[derive(Default)]
+pub struct LoggerRegistry {
+    loggers: BTreeMap<String, Box<dyn Logger + Send>>,
+}
+
+/// Process-global registry. Loggers are not required to be Sync,
+/// so the registry is behind a Mutex rather than a RwLock.
+pub static LOGGER_REGISTRY: Mutex<LoggerRegistry> = Mutex::new(LoggerRegistry::new());
+
+impl std::fmt::Debug for LoggerRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.loggers.keys()).finish()
+    }
+}
+
+impl LoggerRegistry {
+    /// Constructs an empty registry
+    pub const fn new() -> Self {
+        Self {
+            loggers: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a logger, replacing any logger previously registered with the same name
+    pub fn register(&mut self, name: impl Into<String>, logger: Box<dyn Logger + Send>) {
+        self.loggers.insert(name.into(), logger);
+    }
+
+    /// Removes and returns the logger registered with the name
+    pub fn unregister(&mut self, name: &str) -> Option<Box<dyn Logger + Send>> {
+        self.loggers.remove(name)
+    }
+
+    /// Returns the logger registered with the name
+    pub fn get(&self, name: &str) -> Option<&dyn Logger> {
+        self.loggers.get(name).map(|l| l.as_ref() as &dyn Logger)
+    }
+
+    /// Returns the registered names, in sorted order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.loggers.keys().map(String::as_str)
+    }
+
+    /// Sends entries to the named logger.
+    /// Returns ConfigError if no logger is registered with the name
+    pub async fn send(
+        &self,
+        name: &str,
+        sub: &str,
+        entries: Vec<LogEntry>,
+    ) -> Result<(), ServiceLoggingError> {
+        match self.loggers.get(name) {
+            Some(logger) => logger.send(sub, entries).await,
+            None => Err(ServiceLoggingError::ConfigError(format!(
+                "no logger registered as '{}'",
+                name
+            ))),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Pluggable serialization of log entries, for backends with different field mappings
+use crate::{LogEntry, ServiceLoggingError};
+use serde_json::Value;
+use std::fmt;
+
+/// Converts a [LogEntry] to json for a backend. `backend` is the name of the backend
+/// requesting serialization (e.g., `"webhook"`), for serializers shared by several backends.
+pub trait LogEntrySerializer: fmt::Debug + Send + Sync {
+    /// Returns the json representation of the entry. Backends return the error from `send`
+    /// without sending the batch
+    fn serialize(&self, entry: &LogEntry, backend: &str) -> Result<Value, ServiceLoggingError>;
+}
+
+/// Serializes entries with the camelCase field names used for Coralogix,
+/// as in [to_json](LogEntry::to_json)
+#[derive(Clone, Debug, Default)]
+pub struct CoralogixSerializer;
+
+impl LogEntrySerializer for CoralogixSerializer {
+    fn serialize(&self, entry: &LogEntry, _backend: &str) -> Result<Value, ServiceLoggingError> {
+        Ok(serde_json::to_value(entry)?)
+    }
+}
+
+/// Serializes entries as Elastic Common Schema objects, as in [to_ecs](LogEntry::to_ecs)
+#[derive(Clone, Debug, Default)]
+pub struct EcsSerializer;
+
+impl LogEntrySerializer for EcsSerializer {
+    fn serialize(&self, entry: &LogEntry, _backend: &str) -> Result<Value, ServiceLoggingError> {
+        Ok(entry.to_ecs())
+    }
+}
+
+/// Serializes entries as GELF 1.1 objects, as in [to_gelf](LogEntry::to_gelf)
+#[derive(Clone, Debug, Default)]
+pub struct GelfSerializer {
+    /// Value of the GELF `host` field. If empty, `-` is used
+    pub host: String,
+}
+
+impl GelfSerializer {
+    /// Constructs a serializer with the host name
+    pub fn new(host: impl Into<String>) -> Self {
+        Self { host: host.into() }
+    }
+}
+
+impl LogEntrySerializer for GelfSerializer {
+    fn serialize(&self, entry: &LogEntry, _backend: &str) -> Result<Value, ServiceLoggingError> {
+        let host = if self.host.is_empty() {
+            "-"
+        } else {
+            &self.host
+        };
+        Ok(entry.to_gelf(host))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{LogEntry, LogQueue};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Thread-safe LogQueue, for sharing between threads or tasks.
+/// Clones share the same queue. Works with the `log!` macro:
+///
+/// ```
+/// use service_logging::{log, Severity, SharedLogQueue};
+/// let queue = SharedLogQueue::new();
+/// let copy = queue.clone();
+/// std::thread::spawn(move || log!(copy, Severity::Info, text: "hello"))
+///     .join()
+///     .unwrap();
+/// assert_eq!(queue.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SharedLogQueue {
+    queue: Arc<Mutex<LogQueue>>,
+}
+
+impl SharedLogQueue {
+    /// Constructs a new empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // A panic while holding the lock doesn't prevent further logging
+    fn lock(&self) -> MutexGuard<'_, LogQueue> {
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Appends a log entry to the queue
+    pub fn log(&self, e: LogEntry) {
+        self.lock().log(e)
+    }
+
+    /// Returns all queued items, emptying the queue
+    pub fn take(&self) -> Vec<LogEntry> {
+        self.lock().take()
+    }
+
+    /// Returns the number of queued entries
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if there are no items to log
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Returns the shared queue, e.g., for [install_panic_hook](crate::install_panic_hook)
+    pub fn inner(&self) -> Arc<Mutex<LogQueue>> {
+        self.queue.clone()
+    }
+}
+
+impl From<Arc<Mutex<LogQueue>>> for SharedLogQueue {
+    fn from(queue: Arc<Mutex<LogQueue>>) -> Self {
+        Self { queue }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{Logger, ServiceLoggingError};
+use std::ops::Deref;
+
+/// Wrapper that shuts down a logger when it is no longer needed. Since `Drop` can't be async,
+/// call [drop_async](LoggerShutdownGuard::drop_async) to shut down the logger;
+/// if the guard is dropped without it, buffered entries may not be sent.
+///
+/// ```
+/// # async fn run() -> Result<(), service_logging::ServiceLoggingError> {
+/// use service_logging::{BatchLogger, ConsoleLogger, LogEntry, Logger, LoggerShutdownGuard};
+/// let logger = LoggerShutdownGuard::new(BatchLogger::new(ConsoleLogger::init(), 100));
+/// logger.send("sub", vec![LogEntry::default()]).await?;
+/// logger.drop_async().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct LoggerShutdownGuard<L: Logger> {
+    logger: L,
+}
+
+impl<L: Logger> LoggerShutdownGuard<L> {
+    /// Constructs guard for the logger
+    pub fn new(logger: L) -> Self {
+        Self { logger }
+    }
+
+    /// Shuts down the logger, and drops it
+    pub async fn drop_async(self) -> Result<(), ServiceLoggingError> {
+        self.logger.shutdown().await
+    }
+}
+
+impl<L: Logger> Deref for LoggerShutdownGuard<L> {
+    type Target = L;
+
+    fn deref(&self) -> &L {
+        &self.logger
+    }
+}
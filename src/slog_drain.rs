@@ -0,0 +1,92 @@
+// slog integration (feature "slog")
+use crate::time::current_time_millis;
+use crate::{LogEntry, LogQueue, Severity};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Implementation of `slog::Drain` that adds records to a shared LogQueue.
+/// The record message is the entry's `text`, the record tag (if not empty) is its `category`,
+/// and key-value pairs, from both the record and the logger, are added to `custom_fields`.
+/// Source location is always recorded.
+///
+/// ```
+/// # use service_logging::{LogQueue, ServiceLoggingDrain};
+/// # use std::sync::{Arc, Mutex};
+/// let queue = Arc::new(Mutex::new(LogQueue::default()));
+/// let logger = slog::Logger::root(ServiceLoggingDrain::new(queue.clone()), slog::o!());
+/// slog::info!(logger, "hello"; "key" => "value");
+/// assert_eq!(queue.lock().unwrap().len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ServiceLoggingDrain {
+    queue: Arc<Mutex<LogQueue>>,
+}
+
+impl ServiceLoggingDrain {
+    /// Constructs a drain that adds entries to the queue
+    pub fn new(queue: Arc<Mutex<LogQueue>>) -> Self {
+        Self { queue }
+    }
+}
+
+impl From<slog::Level> for Severity {
+    fn from(level: slog::Level) -> Self {
+        match level {
+            slog::Level::Critical => Severity::Critical,
+            slog::Level::Error => Severity::Error,
+            slog::Level::Warning => Severity::Warning,
+            slog::Level::Info => Severity::Info,
+            slog::Level::Debug => Severity::Verbose,
+            slog::Level::Trace => Severity::Debug,
+        }
+    }
+}
+
+// collects key-value pairs into custom_fields
+struct FieldSerializer<'e>(&'e mut LogEntry);
+
+impl<'e> slog::Serializer for FieldSerializer<'e> {
+    fn emit_arguments(&mut self, key: slog::Key, val: &fmt::Arguments<'_>) -> slog::Result {
+        self.0
+            .custom_fields
+            .insert(key.to_string(), val.to_string());
+        Ok(())
+    }
+}
+
+impl slog::Drain for ServiceLoggingDrain {
+    type Ok = ();
+    type Err = slog::Never;
+
+    fn log(
+        &self,
+        record: &slog::Record<'_>,
+        values: &slog::OwnedKVList,
+    ) -> Result<Self::Ok, Self::Err> {
+        let mut entry = LogEntry {
+            timestamp: current_time_millis(),
+            severity: record.level().into(),
+            text: record.msg().to_string(),
+            category: Some(record.tag())
+                .filter(|t| !t.is_empty())
+                .map(String::from),
+            source_file: Some(record.file().to_string()),
+            source_line: Some(record.line()),
+            source_module: Some(record.module().to_string()),
+            ..Default::default()
+        };
+        entry.set_timestamp_micros();
+        {
+            use slog::KV;
+            let mut serializer = FieldSerializer(&mut entry);
+            // logger values first, so record values take precedence
+            let _ = values.serialize(record, &mut serializer);
+            let _ = record.kv().serialize(record, &mut serializer);
+        }
+        self.queue
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .log(entry);
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use crate::time::current_time_millis;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Snapshot of logger statistics
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoggerStats {
+    /// Number of entries sent successfully
+    pub entries_sent: u64,
+    /// Number of failed sends
+    pub send_errors: u64,
+    /// Time of the most recent failed send, milliseconds since epoch in UTC, or 0 if none
+    pub last_error_ms: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    entries_sent: AtomicU64,
+    send_errors: AtomicU64,
+    last_error_ms: AtomicU64,
+}
+
+/// Handle for reading statistics of a logger, which remains usable after the logger is boxed.
+/// Counters use relaxed atomics, so a snapshot taken during a send may be slightly out of date
+#[derive(Clone, Debug, Default)]
+pub struct LoggerStatsHandle {
+    counters: Arc<Counters>,
+}
+
+impl LoggerStatsHandle {
+    /// Returns current statistics
+    pub fn stats(&self) -> LoggerStats {
+        LoggerStats {
+            entries_sent: self.counters.entries_sent.load(Ordering::Relaxed),
+            send_errors: self.counters.send_errors.load(Ordering::Relaxed),
+            last_error_ms: self.counters.last_error_ms.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn record_sent(&self, count: usize) {
+        self.counters
+            .entries_sent
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_error(&self) {
+        self.counters.send_errors.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .last_error_ms
+            .store(current_time_millis(), Ordering::Relaxed);
+    }
+}
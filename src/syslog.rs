@@ -0,0 +1,96 @@
+// syslog (RFC 5424) serialization
+// https://datatracker.ietf.org/doc/html/rfc5424
+use crate::time::format_rfc3339;
+use crate::LogEntry;
+
+// facility for all messages: user-level messages
+const FACILITY_USER: u8 = 1;
+// SD-ID for custom fields. 32473 is the private enterprise number reserved for documentation
+const SD_ID: &str = "fields@32473";
+
+// header fields are printable ascii without spaces, or "-" if empty
+fn header_field(val: &str, max_len: usize) -> String {
+    let s: String = val
+        .chars()
+        .filter(|c| c.is_ascii_graphic())
+        .take(max_len)
+        .collect();
+    if s.is_empty() {
+        "-".to_string()
+    } else {
+        s
+    }
+}
+
+// PARAM-NAME is 1-32 printable ascii chars except '=', ' ', ']', '"'
+fn param_name(key: &str) -> String {
+    key.chars()
+        .filter(|c| c.is_ascii_graphic() && !matches!(c, '=' | ']' | '"'))
+        .take(32)
+        .collect()
+}
+
+// PARAM-VALUE escapes '"', '\', and ']'
+fn param_value(val: &str) -> String {
+    let mut s = String::with_capacity(val.len());
+    for c in val.chars() {
+        if matches!(c, '"' | '\\' | ']') {
+            s.push('\\');
+        }
+        s.push(c);
+    }
+    s
+}
+
+impl LogEntry {
+    /// Returns this entry as an RFC 5424 syslog message.
+    /// MSGID is the entry's `category`. `class_name`, `method_name`, `thread_id`, and
+    /// `custom_fields` are included as structured data.
+    pub fn to_syslog_line(&self, hostname: &str, app_name: &str, proc_id: &str) -> String {
+        let prival = FACILITY_USER * 8 + self.severity.syslog_level();
+        let msg_id = header_field(self.category.as_deref().unwrap_or_default(), 32);
+
+        let mut params = Vec::new();
+        for (key, val) in [
+            ("class_name", &self.class_name),
+            ("method_name", &self.method_name),
+            ("thread_id", &self.thread_id),
+        ] {
+            if let Some(val) = val {
+                params.push((key.to_string(), val.as_str()));
+            }
+        }
+        for (key, val) in self.custom_fields.iter() {
+            let name = param_name(key);
+            if !name.is_empty() {
+                params.push((name, val.as_str()));
+            }
+        }
+        let sd = if params.is_empty() {
+            "-".to_string()
+        } else {
+            let mut sd = format!("[{}", SD_ID);
+            for (name, val) in params {
+                sd.push_str(&format!(" {}=\"{}\"", name, param_value(val)));
+            }
+            sd.push(']');
+            sd
+        };
+
+        let mut line = format!(
+            "<{}>1 {} {} {} {} {} {}",
+            prival,
+            format_rfc3339(self.timestamp),
+            header_field(hostname, 255),
+            header_field(app_name, 48),
+            header_field(proc_id, 128),
+            msg_id,
+            sd
+        );
+        if !self.text.is_empty() {
+            line.push(' ');
+            line.push_str(&self.text);
+        }
+        line
+    }
+}
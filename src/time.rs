@@ -1,5 +1,8 @@
+#[cfg(nostd)]
+use alloc::{format, string::String};
+
 /// Returns current time in UTC, as integer milliseconds since EPOCH
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(not(nostd), target_arch = "wasm32"))]
 // logging api supports floating pt timestamps for fractional millis,
 // but we don't need that resolution, and serde float support is bulky
 pub fn current_time_millis() -> u64 {
@@ -7,7 +10,7 @@ pub fn current_time_millis() -> u64 {
 }
 
 /// Returns current time in UTC, as integer milliseconds since EPOCH
-#[cfg(not(target_arch = "wasm32"))]
+#[cfg(all(not(nostd), not(target_arch = "wasm32")))]
 pub fn current_time_millis() -> u64 {
     use std::time::SystemTime;
     match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
@@ -15,3 +18,61 @@ pub fn current_time_millis() -> u64 {
         Err(_) => 0, // panic!("SystemTime before UNIX EPOCH!"),
     }
 }
+
+/// Returns current time in UTC, as integer microseconds since EPOCH
+#[cfg(all(not(nostd), target_arch = "wasm32"))]
+pub fn current_time_micros() -> u64 {
+    use wasm_bindgen::JsCast;
+    // Performance is available in browsers and workers (as a global), with sub-millisecond resolution
+    let perf = js_sys::Reflect::get(&js_sys::global(), &"performance".into())
+        .ok()
+        .and_then(|p| p.dyn_into::<web_sys::Performance>().ok());
+    match perf {
+        Some(p) => ((p.time_origin() + p.now()) * 1000.0) as u64,
+        None => (js_sys::Date::now() * 1000.0) as u64,
+    }
+}
+
+/// Returns current time in UTC, as integer microseconds since EPOCH
+#[cfg(all(not(nostd), not(target_arch = "wasm32")))]
+pub fn current_time_micros() -> u64 {
+    use std::time::SystemTime;
+    match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(n) => n.as_micros() as u64,
+        Err(_) => 0,
+    }
+}
+
+/// Formats milliseconds since EPOCH as an RFC 3339 (ISO 8601) UTC timestamp with
+/// millisecond precision, e.g., `2020-09-13T12:26:40.123Z`
+pub fn format_rfc3339(millis: u64) -> String {
+    let secs = millis / 1000;
+    let days = (secs / 86400) as i64;
+    let rem = secs % 86400;
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60,
+        millis % 1000
+    )
+}
+
+// converts days since 1970-01-01 to (year, month, day)
+// algorithm from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
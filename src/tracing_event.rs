@@ -0,0 +1,63 @@
+// tracing crate integration (feature "tracing")
+use crate::{LogEntry, Severity};
+use std::fmt;
+use tracing::field::{Field, Visit};
+
+impl From<tracing::Level> for Severity {
+    /// `TRACE` is `Debug`, the most verbose severity, and `DEBUG` is `Verbose`
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::ERROR => Severity::Error,
+            tracing::Level::WARN => Severity::Warning,
+            tracing::Level::INFO => Severity::Info,
+            tracing::Level::DEBUG => Severity::Verbose,
+            _ => Severity::Debug,
+        }
+    }
+}
+
+// collects the message into text, and other fields into custom_fields
+struct FieldVisitor<'e>(&'e mut LogEntry);
+
+impl<'e> Visit for FieldVisitor<'e> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.0.text = value.to_string();
+        } else {
+            self.0
+                .custom_fields
+                .insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0.text = format!("{:?}", value);
+        } else {
+            self.0
+                .custom_fields
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+}
+
+/// The `message` field is the entry's `text`, and other fields are added to `custom_fields`.
+/// The name of the current span, if any, is the entry's `category`. The current span is
+/// not available to subscribers set with `tracing::subscriber::with_default`.
+/// The file and line are recorded in `source_file` and `source_line`
+impl From<&tracing::Event<'_>> for LogEntry {
+    fn from(event: &tracing::Event<'_>) -> Self {
+        let meta = event.metadata();
+        let mut entry = LogEntry {
+            severity: Severity::from(*meta.level()),
+            category: tracing::Span::current()
+                .metadata()
+                .map(|m| m.name().to_string()),
+            source_file: meta.file().map(String::from),
+            source_line: meta.line(),
+            ..Default::default()
+        };
+        event.record(&mut FieldVisitor(&mut entry));
+        entry
+    }
+}
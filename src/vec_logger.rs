@@ -0,0 +1,71 @@
+use crate::{LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+type Captured = Arc<Mutex<Vec<(String, LogEntry)>>>;
+
+// lock captured entries. A panic in another thread doesn't prevent inspection
+fn lock(captured: &Captured) -> MutexGuard<'_, Vec<(String, LogEntry)>> {
+    captured.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Logger that stores entries in memory, for use in tests. Clones share the same entries.
+///
+/// ```
+/// # async fn run() {
+/// use service_logging::{log, LogQueue, Severity, VecLogger};
+/// let (logger, handle) = VecLogger::new();
+/// let mut lq = LogQueue::default();
+/// log!(lq, Severity::Info, text: "hello");
+/// logger.send("sub", lq.take()).await.unwrap();
+/// assert!(handle.has_entry_matching(|e| e.text == "hello"));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VecLogger {
+    captured: Captured,
+}
+
+/// Handle for inspecting entries received by a [VecLogger]
+#[derive(Clone, Debug)]
+pub struct VecLoggerHandle {
+    captured: Captured,
+}
+
+impl VecLogger {
+    /// Constructs a logger and a handle for inspecting the entries it receives
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> (Box<dyn Logger + Send>, VecLoggerHandle) {
+        let logger = VecLogger::default();
+        let handle = VecLoggerHandle {
+            captured: logger.captured.clone(),
+        };
+        (Box::new(logger), handle)
+    }
+}
+
+impl VecLoggerHandle {
+    /// Returns a copy of all entries received, with their subsystem names, in the order received
+    pub fn entries(&self) -> Vec<(String, LogEntry)> {
+        lock(&self.captured).clone()
+    }
+
+    /// Returns true if any entry received matches the predicate
+    pub fn has_entry_matching(&self, f: impl Fn(&LogEntry) -> bool) -> bool {
+        lock(&self.captured).iter().any(|(_, e)| f(e))
+    }
+
+    /// Removes all captured entries
+    pub fn clear(&self) {
+        lock(&self.captured).clear()
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for VecLogger {
+    /// Stores entries in memory
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        lock(&self.captured).extend(entries.into_iter().map(|e| (sub.to_string(), e)));
+        Ok(())
+    }
+}
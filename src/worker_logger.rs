@@ -0,0 +1,53 @@
+use crate::{CoralogixConfig, CoralogixLogger, LogEntry, Logger, ServiceLoggingError};
+use async_trait::async_trait;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Logger for Cloudflare Workers that sends entries to Coralogix in the background,
+/// using `ctx.waitUntil()`, so that sending doesn't delay the response.
+/// `send` returns as soon as the request is scheduled; errors from the background send
+/// are written to console.error.
+///
+/// ```ignore
+/// let logger = WorkerLogger::init(&ctx, config)?;
+/// logger.send("http", log_queue.take()).await?;
+/// Ok(response)
+/// ```
+pub struct WorkerLogger {
+    ctx: worker::Context,
+    logger: CoralogixLogger,
+}
+
+impl WorkerLogger {
+    /// Initialize logger for the fetch event with the context `ctx`.
+    /// Returns an error if the configuration is not [valid](CoralogixConfig::validate)
+    pub fn init(
+        ctx: &worker::Context,
+        config: CoralogixConfig,
+    ) -> Result<Box<dyn Logger + Send>, ServiceLoggingError> {
+        // worker::Context isn't Clone, but the underlying js object can be shared
+        let js: &worker::worker_sys::Context = ctx.as_ref();
+        let js: &JsValue = js.as_ref();
+        Ok(Box::new(Self {
+            ctx: worker::Context::new(js.clone().unchecked_into()),
+            logger: CoralogixLogger::new(config)?,
+        }))
+    }
+}
+
+#[async_trait(?Send)]
+impl Logger for WorkerLogger {
+    /// Schedules entries to be sent after the response is returned
+    async fn send(&self, sub: &'_ str, entries: Vec<LogEntry>) -> Result<(), ServiceLoggingError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let logger = self.logger.clone();
+        let sub = sub.to_string();
+        self.ctx.wait_until(async move {
+            if let Err(e) = logger.send(&sub, entries).await {
+                web_sys::console::error_1(&format!("WorkerLogger send error: {}", e).into());
+            }
+        });
+        Ok(())
+    }
+}
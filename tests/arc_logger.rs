@@ -0,0 +1,32 @@
+// Logger impl for Arc
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{
+    silent_logger, CloneableLogger, ConsoleLogger, ConsoleLoggerConfig, LogEntry, Logger, VecLogger,
+};
+use std::sync::Arc;
+
+async fn send_one(logger: &impl Logger) {
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+}
+
+#[tokio::test]
+async fn arc_logger() {
+    let console = Arc::new(ConsoleLogger::new(ConsoleLoggerConfig::default()));
+    send_one(&console).await;
+    assert!(console.health_check().await.is_ok());
+
+    send_one(&silent_logger()).await;
+
+    let shared: Arc<dyn Logger + Send + Sync> = Arc::new(VecLogger::default());
+    send_one(&shared).await;
+    // Arc is Clone, so shared loggers are also CloneableLogger
+    let cloned = shared.clone_logger();
+    cloned
+        .send("other", vec![LogEntry::default()])
+        .await
+        .expect("send");
+}
@@ -0,0 +1,42 @@
+// AsyncLogQueue
+//
+#![cfg(feature = "async-queue")]
+use service_logging::{log, AsyncLogQueue, LogEntry, Severity};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn async_queue_concurrent_senders() {
+    let (sender, mut receiver) = AsyncLogQueue::new(1000);
+    let tasks: Vec<_> = (0..10)
+        .map(|task| {
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                for n in 0..100 {
+                    log!(sender, Severity::Info, text: "entry", task: task, n: n);
+                }
+            })
+        })
+        .collect();
+    for t in tasks {
+        t.await.unwrap();
+    }
+    let entries = receiver.take_all().await;
+    assert_eq!(entries.len(), 1000);
+    assert_eq!(sender.dropped(), 0);
+    assert!(receiver.take_all().await.is_empty());
+}
+
+#[tokio::test]
+async fn async_queue_full() {
+    let (sender, mut receiver) = AsyncLogQueue::new(2);
+    for _ in 0..5 {
+        sender.log(LogEntry::default());
+    }
+    assert_eq!(sender.dropped(), 3);
+    assert_eq!(receiver.dropped(), 3);
+    assert_eq!(receiver.take_queue().await.len(), 2);
+
+    sender.log(LogEntry::default());
+    assert!(receiver.recv().await.is_some());
+    drop(sender);
+    assert!(receiver.recv().await.is_none());
+}
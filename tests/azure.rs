@@ -0,0 +1,52 @@
+// AzureMonitorLogger
+//
+#![cfg(feature = "azure")]
+mod common;
+use common::MockServer;
+use service_logging::{AzureMonitorConfig, AzureMonitorLogger, LogEntry, Severity};
+
+#[tokio::test]
+async fn azure_send() {
+    let server = MockServer::start();
+    let logger = AzureMonitorLogger::init(AzureMonitorConfig {
+        instrumentation_key: "ikey".to_string(),
+        endpoint: format!("{}/v2/track", server.url),
+    })
+    .expect("init");
+
+    let entries: Vec<LogEntry> = Severity::iter()
+        .map(|severity| LogEntry {
+            timestamp: 1_600_000_000_123,
+            severity,
+            text: "msg".to_string(),
+            category: Some("db".to_string()),
+            ..Default::default()
+        })
+        .collect();
+    logger.send("web-api", entries).await.expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let req = &requests[0];
+    assert_eq!(req.path, "/v2/track");
+    // the track api is authenticated by iKey, without Data Collector api headers
+    assert_eq!(req.header("log-type"), None);
+    assert_eq!(req.header("x-ms-date"), None);
+
+    let body = req.json();
+    let envelopes = body.as_array().expect("array");
+    assert_eq!(envelopes.len(), 6);
+    let e = &envelopes[2];
+    assert_eq!(e["name"], "Microsoft.ApplicationInsights.Message");
+    assert_eq!(e["time"], "2020-09-13T12:26:40.123Z");
+    assert_eq!(e["iKey"], "ikey");
+    assert_eq!(e["data"]["baseType"], "MessageData");
+    assert_eq!(e["data"]["baseData"]["message"], "msg");
+    assert_eq!(e["data"]["baseData"]["properties"]["category"], "db");
+    assert_eq!(e["data"]["baseData"]["properties"]["subsystem"], "web-api");
+    let levels: Vec<u64> = envelopes
+        .iter()
+        .map(|e| e["data"]["baseData"]["severityLevel"].as_u64().unwrap())
+        .collect();
+    assert_eq!(levels, vec![0, 0, 1, 2, 3, 4]);
+}
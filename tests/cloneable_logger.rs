@@ -0,0 +1,47 @@
+// CloneableLogger
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{CloneableLogger, ConsoleLogger, LogEntry, Logger, MockLogger};
+
+fn entry(text: &str) -> LogEntry {
+    LogEntry {
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn clone_boxed_logger() {
+    let first = MockLogger::new();
+    let second = MockLogger::new();
+    let a: Box<dyn CloneableLogger> = Box::new(first.clone());
+    let b = a.clone();
+    let c: Box<dyn CloneableLogger> = Box::new(second.clone());
+
+    a.send("sub", vec![entry("from a")]).await.unwrap();
+    b.send("sub", vec![entry("from b")]).await.unwrap();
+    c.send("sub", vec![entry("from c")]).await.unwrap();
+
+    // MockLogger clones share captured entries
+    let texts: Vec<String> = first
+        .captured_entries()
+        .into_iter()
+        .map(|e| e.text)
+        .collect();
+    assert_eq!(texts, vec!["from a", "from b"]);
+    assert_eq!(second.captured_entries().len(), 1);
+
+    // a failed send from one copy does not affect the other
+    first.fail_next(1);
+    assert!(b.send("sub", vec![entry("fails")]).await.is_err());
+    a.send("sub", vec![entry("again")]).await.unwrap();
+    assert_eq!(first.captured_entries().len(), 3);
+}
+
+#[tokio::test]
+async fn clone_logger_as_logger() {
+    let console: Box<dyn CloneableLogger> = Box::new(ConsoleLogger::default());
+    let copy: Box<dyn Logger + Send> = console.clone_logger();
+    copy.send("sub", vec![entry("hello")]).await.unwrap();
+    console.send("sub", vec![entry("hello")]).await.unwrap();
+}
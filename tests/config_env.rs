@@ -0,0 +1,42 @@
+// CoralogixConfig::from_env
+// (one test, since environment variables are process-global)
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{ConfigError, CoralogixConfig, CoralogixLogger};
+use std::env;
+
+#[test]
+fn config_from_env() {
+    env::set_var("CORALOGIX_API_KEY", "key");
+    env::set_var("CORALOGIX_APP_NAME", "app");
+    env::set_var(
+        "CORALOGIX_ENDPOINT",
+        "https://api.coralogix.com/api/v1/logs",
+    );
+    env::remove_var("CORALOGIX_COMPUTER_NAME");
+
+    let config = CoralogixConfig::from_env().expect("config");
+    assert_eq!(config.api_key, "key");
+    assert_eq!(config.application_name, "app");
+    assert_eq!(config.endpoint, "https://api.coralogix.com/api/v1/logs");
+    assert_eq!(config.computer_name, None);
+    assert!(CoralogixLogger::init(config.as_config()).is_ok());
+
+    env::set_var("CORALOGIX_COMPUTER_NAME", "host1");
+    let config = CoralogixConfig::from_env().expect("config");
+    assert_eq!(config.computer_name.as_deref(), Some("host1"));
+
+    env::remove_var("CORALOGIX_APP_NAME");
+    let err = CoralogixConfig::from_env().expect_err("missing");
+    assert_eq!(
+        err,
+        ConfigError::MissingVar("CORALOGIX_APP_NAME".to_string())
+    );
+    assert!(err.to_string().contains("CORALOGIX_APP_NAME"));
+
+    env::set_var("CORALOGIX_APP_NAME", "app");
+    env::set_var("CORALOGIX_API_KEY", "");
+    let err = CoralogixConfig::from_env().expect_err("empty");
+    assert_eq!(err, ConfigError::EmptyVar("CORALOGIX_API_KEY".to_string()));
+    assert!(err.to_string().contains("CORALOGIX_API_KEY"));
+}
@@ -0,0 +1,86 @@
+// CoralogixConfig::validate
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{ConfigError, CoralogixConfig, CoralogixLogger, ServiceLoggingError};
+
+fn config<'a>(api_key: &'a str, endpoint: &'a str) -> CoralogixConfig<'a> {
+    CoralogixConfig {
+        api_key,
+        application_name: "app",
+        endpoint,
+        ..Default::default()
+    }
+}
+
+const ENDPOINT: &str = "https://api.coralogix.com/api/v1/logs";
+
+#[test]
+fn valid_config() {
+    assert_eq!(config("key", ENDPOINT).validate(), Ok(()));
+    // http is allowed for loopback addresses
+    assert_eq!(
+        config("key", "http://127.0.0.1:8080/logs").validate(),
+        Ok(())
+    );
+    assert_eq!(config("key", "http://localhost/logs").validate(), Ok(()));
+    assert!(CoralogixLogger::init(config("key", ENDPOINT)).is_ok());
+}
+
+#[test]
+fn empty_api_key() {
+    assert_eq!(
+        config("", ENDPOINT).validate(),
+        Err(vec![ConfigError::EmptyField("api_key".to_string())])
+    );
+}
+
+#[test]
+fn invalid_url() {
+    let errors = config("key", "not a url").validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(errors[0], ConfigError::InvalidUrl(_)));
+
+    let errors = config("key", "http://api.coralogix.com/api/v1/logs")
+        .validate()
+        .unwrap_err();
+    assert!(matches!(&errors[..], [ConfigError::InvalidUrl(msg)] if msg.contains("https")));
+}
+
+#[test]
+fn multiple_errors() {
+    let errors = config("", "ftp://example.com").validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0], ConfigError::EmptyField("api_key".to_string()));
+    assert!(matches!(errors[1], ConfigError::InvalidUrl(_)));
+
+    let long = "x".repeat(300);
+    let errors = CoralogixConfig {
+        application_name: "",
+        computer_name: Some(&long),
+        ..config("key", ENDPOINT)
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            ConfigError::EmptyField("application_name".to_string()),
+            ConfigError::FieldTooLong {
+                field: "computer_name".to_string(),
+                max: 256
+            },
+        ]
+    );
+}
+
+#[test]
+fn init_validates() {
+    match CoralogixLogger::init(config("", "not a url")) {
+        Err(ServiceLoggingError::ConfigError(msg)) => {
+            assert!(msg.contains("api_key is empty"), "{}", msg);
+            assert!(msg.contains("invalid endpoint"), "{}", msg);
+        }
+        Err(e) => panic!("unexpected error {}", e),
+        Ok(_) => panic!("expected error"),
+    }
+}
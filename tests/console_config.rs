@@ -0,0 +1,192 @@
+// ConsoleLogger configuration
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{
+    ConsoleLogger, ConsoleLoggerConfig, LogEntry, LogFormat, Severity, TimestampFormat,
+};
+
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip to end of escape sequence
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn entry(severity: Severity) -> LogEntry {
+    LogEntry {
+        timestamp: 1234,
+        severity,
+        text: "hello".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn colorized_severity() {
+    let colors = ConsoleLogger::new(ConsoleLoggerConfig {
+        colorize: true,
+        ..Default::default()
+    });
+    let plain = ConsoleLogger::new(ConsoleLoggerConfig::default());
+    for (severity, label) in [
+        (Severity::Debug, "Debug"),
+        (Severity::Verbose, "Verbose"),
+        (Severity::Info, "Info"),
+        (Severity::Warning, "Warning"),
+        (Severity::Error, "Error"),
+        (Severity::Critical, "Critical"),
+    ] {
+        let e = entry(severity);
+        let colored = colors.format_entry("sub", &e);
+        assert!(colored.contains('\x1b'));
+        let expected = format!("1234 sub {} hello", label);
+        assert_eq!(strip_ansi(&colored), expected);
+        assert_eq!(plain.format_entry("sub", &e), expected);
+    }
+    assert!(colors
+        .format_entry("sub", &entry(Severity::Error))
+        .contains("\x1b[31mError\x1b[0m"));
+}
+
+#[test]
+fn without_timestamp() {
+    let logger = ConsoleLogger::new(ConsoleLoggerConfig {
+        include_timestamp: false,
+        ..Default::default()
+    });
+    assert_eq!(
+        logger.format_entry("sub", &entry(Severity::Info)),
+        "sub Info hello"
+    );
+}
+
+#[test]
+fn json_format() {
+    let logger = ConsoleLogger::new(ConsoleLoggerConfig {
+        format: LogFormat::Json,
+        colorize: true,
+        ..Default::default()
+    });
+    let mut e = entry(Severity::Warning);
+    e.category = Some("http".to_string());
+    let line = logger.format_entry("sub", &e);
+    assert!(!line.contains('\n'));
+
+    let v: serde_json::Value = serde_json::from_str(&line).expect("json");
+    assert_eq!(v["timestamp"], 1234);
+    assert_eq!(v["severity"], 4);
+    assert_eq!(v["text"], "hello");
+    assert_eq!(v["category"], "http");
+    assert_eq!(v["subsystem"], "sub");
+}
+
+#[tokio::test]
+async fn json_logger_send() {
+    let logger = ConsoleLogger::init_with_format(LogFormat::Json);
+    logger
+        .send("test_json", vec![entry(Severity::Info)])
+        .await
+        .expect("send");
+}
+
+#[test]
+fn timestamp_formats() {
+    let format = |timestamp_format| {
+        let logger = ConsoleLogger::new(ConsoleLoggerConfig {
+            timestamp_format,
+            ..Default::default()
+        });
+        let e = LogEntry {
+            timestamp: 1_600_000_000_123,
+            ..entry(Severity::Info)
+        };
+        let line = logger.format_entry("sub", &e);
+        line.split(' ').next().unwrap().to_string()
+    };
+    assert_eq!(format(TimestampFormat::UnixMillis), "1600000000123");
+
+    let rfc3339 = format(TimestampFormat::Rfc3339);
+    let re = regex::Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})$")
+        .unwrap();
+    assert!(re.is_match(&rfc3339), "{}", rfc3339);
+    assert_eq!(rfc3339, "2020-09-13T12:26:40.123Z");
+
+    let relative = format(TimestampFormat::Relative {
+        base_ms: 1_600_000_000_000,
+    });
+    assert!(relative.starts_with('+'));
+    assert_eq!(relative, "+123ms");
+}
+
+#[test]
+fn padded_columns() {
+    let logger = ConsoleLogger::new(ConsoleLoggerConfig {
+        include_timestamp: false,
+        subsystem_width: Some(10),
+        severity_width: Some(8),
+        ..Default::default()
+    });
+    let line = logger.format_entry("api", &entry(Severity::Info));
+    assert_eq!(line, "api        Info     hello");
+    assert_eq!(line.find("Info"), Some(11));
+    let line = logger.format_entry("storage", &entry(Severity::Critical));
+    assert_eq!(line, "storage    Critical hello");
+    // longer names aren't truncated
+    let line = logger.format_entry("long-subsystem", &entry(Severity::Error));
+    assert_eq!(line, "long-subsystem Error    hello");
+
+    let colors = ConsoleLogger::new(ConsoleLoggerConfig {
+        colorize: true,
+        include_timestamp: false,
+        subsystem_width: Some(10),
+        severity_width: Some(8),
+        ..Default::default()
+    });
+    assert_eq!(
+        strip_ansi(&colors.format_entry("api", &entry(Severity::Info))),
+        "api        Info     hello"
+    );
+}
+
+// Vec<u8> writer that can be read after the logger is boxed
+#[derive(Clone, Default)]
+struct Captured(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn with_writer() {
+    let captured = Captured::default();
+    let logger = ConsoleLogger::init_with_writer(Box::new(captured.clone()));
+    logger
+        .send(
+            "sub",
+            vec![entry(Severity::Warning), {
+                let mut e = entry(Severity::Error);
+                e.text = "failed".to_string();
+                e
+            }],
+        )
+        .await
+        .expect("send");
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    assert_eq!(output, "1234 sub Warning hello\n1234 sub Error failed\n");
+}
@@ -1,3 +1,4 @@
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
 wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 // using ConsoleLogger to write to javascript/browser console.log
 
@@ -0,0 +1,58 @@
+// ContextualLogger
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{ContextualLogger, LogEntry, Logger, VecLogger};
+use std::collections::BTreeMap;
+
+fn context() -> BTreeMap<String, String> {
+    let mut context = BTreeMap::new();
+    context.insert("region".to_string(), "us-east-1".to_string());
+    context.insert("service".to_string(), "api".to_string());
+    context
+}
+
+#[tokio::test]
+async fn adds_context_fields() {
+    let (inner, handle) = VecLogger::new();
+    let logger = ContextualLogger::new(inner, context());
+    assert_eq!(logger.context(), &context());
+    logger
+        .send("sub", vec![LogEntry::default(), LogEntry::default()])
+        .await
+        .expect("send");
+    let entries = handle.entries();
+    assert_eq!(entries.len(), 2);
+    for (sub, entry) in entries.iter() {
+        assert_eq!(sub, "sub");
+        assert_eq!(entry.custom_fields, context());
+    }
+}
+
+#[tokio::test]
+async fn existing_fields_not_overridden() {
+    let (inner, handle) = VecLogger::new();
+    let logger = ContextualLogger::new(inner, context());
+    let mut entry = LogEntry::default();
+    entry
+        .custom_fields
+        .insert("region".to_string(), "eu-west-1".to_string());
+    entry
+        .custom_fields
+        .insert("user".to_string(), "alice".to_string());
+    logger.send("sub", vec![entry]).await.expect("send");
+    let (_, entry) = &handle.entries()[0];
+    assert_eq!(entry.custom_fields["region"], "eu-west-1");
+    assert_eq!(entry.custom_fields["service"], "api");
+    assert_eq!(entry.custom_fields["user"], "alice");
+}
+
+#[tokio::test]
+async fn empty_context() {
+    let (inner, handle) = VecLogger::new();
+    let logger = ContextualLogger::new(inner, BTreeMap::new());
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    assert!(handle.entries()[0].1.custom_fields.is_empty());
+}
@@ -0,0 +1,40 @@
+// CoralogixLogger::with_application_name_fn
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixConfig, CoralogixLogger, LogEntry, Logger};
+use std::sync::{Arc, RwLock};
+
+#[tokio::test]
+async fn dynamic_application_name() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let app_name = Arc::new(RwLock::new("first".to_string()));
+    let reader = app_name.clone();
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "static",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init")
+    .with_application_name_fn(Box::new(move || reader.read().unwrap().clone()));
+
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    *app_name.write().unwrap() = "reloaded".to_string();
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+
+    let names: Vec<String> = server
+        .requests()
+        .iter()
+        .map(|r| r.json()["applicationName"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, vec!["first", "reloaded"]);
+}
@@ -0,0 +1,65 @@
+// CoralogixOtlpLogger
+//
+#![cfg(feature = "otel")]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixOtlpConfig, CoralogixOtlpLogger, LogEntry, Severity};
+
+fn config(endpoint: &str) -> CoralogixOtlpConfig {
+    CoralogixOtlpConfig {
+        api_key: "cxtp_secret".to_string(),
+        endpoint: endpoint.to_string(),
+        application_name: "shop".to_string(),
+        subsystem_name: "default-sub".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn coralogix_otlp_send() {
+    let server = MockServer::start();
+    let logger = CoralogixOtlpLogger::init(config(&server.url)).expect("init");
+    let entry = LogEntry {
+        severity: Severity::Error,
+        text: "payment failed".to_string(),
+        ..Default::default()
+    };
+    logger.send("checkout", vec![entry]).await.expect("send");
+    logger
+        .send("", vec![LogEntry::default()])
+        .await
+        .expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let req = &requests[0];
+    assert_eq!(req.path, "/v1/logs");
+    assert_eq!(req.header("authorization"), Some("Bearer cxtp_secret"));
+
+    let resource_attr = |req: &common::Request, key: &str| {
+        req.json()["resourceLogs"][0]["resource"]["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["key"] == key)
+            .map(|a| a["value"]["stringValue"].clone())
+            .unwrap()
+    };
+    assert_eq!(resource_attr(req, "cx.application.name"), "shop");
+    assert_eq!(resource_attr(req, "cx.subsystem.name"), "checkout");
+    let record = &req.json()["resourceLogs"][0]["scopeLogs"][0]["logRecords"][0];
+    assert_eq!(record["body"]["stringValue"], "payment failed");
+    assert_eq!(record["severityNumber"], 17);
+
+    // empty subsystem uses the configured name
+    assert_eq!(
+        resource_attr(&requests[1], "cx.subsystem.name"),
+        "default-sub"
+    );
+}
+
+#[test]
+fn coralogix_otlp_invalid_key() {
+    let mut config = config("http://localhost");
+    config.api_key = "bad\nkey".to_string();
+    assert!(CoralogixOtlpLogger::init(config).is_err());
+}
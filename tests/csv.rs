@@ -0,0 +1,44 @@
+// CSV serialization
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LogQueue, Severity};
+
+#[test]
+fn csv_rows() {
+    let mut with_custom = LogEntry {
+        timestamp: 2,
+        severity: Severity::Error,
+        text: "say \"hi\"\nbye".to_string(),
+        ..Default::default()
+    };
+    with_custom
+        .custom_fields
+        .insert("user".into(), "bob".into());
+    let log_queue = LogQueue::from(vec![
+        LogEntry {
+            timestamp: 1,
+            severity: Severity::Info,
+            text: "one, two".to_string(),
+            category: Some("http".to_string()),
+            ..Default::default()
+        },
+        with_custom,
+    ]);
+
+    let csv = log_queue.to_csv(&["severity", "timestamp", "text", "category", "user"]);
+    assert_eq!(
+        csv,
+        "severity,timestamp,text,category,user\n\
+         Info,1,\"one, two\",http,\n\
+         Error,2,\"say \"\"hi\"\"\nbye\",,bob\n"
+    );
+}
+
+#[test]
+fn csv_empty_queue() {
+    let log_queue = LogQueue::default();
+    assert_eq!(
+        log_queue.to_csv(&["timestamp", "severity", "text"]),
+        "timestamp,severity,text\n"
+    );
+}
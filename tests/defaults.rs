@@ -0,0 +1,71 @@
+// LogQueueWithDefaults stamps default fields
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{log, LogEntry, LogEntryDefaults, LogQueue, Severity};
+
+#[test]
+fn defaults_applied() {
+    let mut log_queue = LogQueue::with_defaults(LogEntryDefaults {
+        environment: Some("staging".to_string()),
+        app_version: Some("1.2.3".to_string()),
+        hostname: Some("web-1".to_string()),
+        ..Default::default()
+    });
+    log!(log_queue, Severity::Info, text: "hello");
+    log_queue.log(LogEntry {
+        environment: Some("production".to_string()),
+        hostname: Some("web-2".to_string()),
+        ..Default::default()
+    });
+
+    let entries = log_queue.take();
+    assert_eq!(entries[0].environment.as_deref(), Some("staging"));
+    assert_eq!(entries[0].app_version.as_deref(), Some("1.2.3"));
+    assert_eq!(entries[1].environment.as_deref(), Some("production"));
+    assert_eq!(entries[1].app_version.as_deref(), Some("1.2.3"));
+    assert_eq!(entries[0].hostname.as_deref(), Some("web-1"));
+    assert_eq!(entries[1].hostname.as_deref(), Some("web-2"));
+    assert!(log_queue.is_empty());
+
+    let json = entries[0].to_json().unwrap();
+    assert!(json.contains(r#""environment":"staging""#));
+    assert!(json.contains(r#""appVersion":"1.2.3""#));
+}
+
+#[test]
+fn defaults_all_fields() {
+    let mut defaults = LogEntryDefaults {
+        category: Some("http".to_string()),
+        thread_id: Some("main".to_string()),
+        trace_id: Some("t0".to_string()),
+        tags: vec!["api".to_string()],
+        ..Default::default()
+    };
+    defaults
+        .custom_fields
+        .insert("hostname".into(), "web-1".into());
+    defaults
+        .custom_fields
+        .insert("correlation_id".into(), "c0".into());
+    let mut log_queue = LogQueue::with_defaults(defaults);
+
+    log!(log_queue, Severity::Info, text: "defaulted");
+    log!(log_queue, Severity::Info, text: "explicit", category: "db", correlation_id: "c1", tags: "api,slow");
+
+    let entries = log_queue.take();
+    let e = &entries[0];
+    assert_eq!(e.category.as_deref(), Some("http"));
+    assert_eq!(e.thread_id.as_deref(), Some("main"));
+    assert_eq!(e.trace_id.as_deref(), Some("t0"));
+    assert_eq!(e.class_name, None);
+    assert_eq!(e.custom_fields.get("hostname").unwrap(), "web-1");
+    assert_eq!(e.custom_fields.get("correlation_id").unwrap(), "c0");
+    assert_eq!(e.tags, vec!["api".to_string()]);
+
+    // explicitly set fields take precedence
+    let e = &entries[1];
+    assert_eq!(e.category.as_deref(), Some("db"));
+    assert_eq!(e.custom_fields.get("correlation_id").unwrap(), "c1");
+    assert_eq!(e.custom_fields.get("hostname").unwrap(), "web-1");
+    assert_eq!(e.tags, vec!["api".to_string(), "slow".to_string()]);
+}
@@ -0,0 +1,39 @@
+// LogQueue::drain_by_severity
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+#[test]
+fn drain_by_severity() {
+    let severities = [
+        Severity::Debug,
+        Severity::Error,
+        Severity::Info,
+        Severity::Critical,
+        Severity::Warning,
+        Severity::Warning,
+    ];
+    let mut lq = LogQueue::from(
+        severities
+            .iter()
+            .enumerate()
+            .map(|(n, s)| LogEntry {
+                severity: s.clone(),
+                text: n.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+    );
+
+    let (below, above) = lq.drain_by_severity(Severity::Warning);
+    assert!(lq.is_empty());
+    assert_eq!(below.len() + above.len(), severities.len());
+    assert!(below.iter().all(|e| e.severity < Severity::Warning));
+    assert!(above.iter().all(|e| e.severity >= Severity::Warning));
+
+    let texts = |v: &[LogEntry]| v.iter().map(|e| e.text.clone()).collect::<Vec<_>>();
+    assert_eq!(texts(&below), vec!["0", "2"]);
+    assert_eq!(texts(&above), vec!["1", "3", "4", "5"]);
+
+    let (below, above) = lq.drain_by_severity(Severity::Warning);
+    assert!(below.is_empty() && above.is_empty());
+}
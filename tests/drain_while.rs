@@ -0,0 +1,39 @@
+// LogQueue::drain_while
+//
+use service_logging::{LogEntry, LogQueue};
+
+fn queue() -> LogQueue {
+    LogQueue::from(
+        [10, 20, 30, 5, 40]
+            .iter()
+            .map(|ts| LogEntry::with_timestamp(*ts))
+            .collect(),
+    )
+}
+
+fn timestamps(entries: &[LogEntry]) -> Vec<u64> {
+    entries.iter().map(|e| e.timestamp).collect()
+}
+
+#[test]
+fn drain_while() {
+    let mut lq = queue();
+    let drained = lq.drain_while(|e| e.timestamp < 25);
+    assert_eq!(timestamps(&drained), vec![10, 20]);
+    // 5 matches, but is after the first non-matching entry
+    assert_eq!(timestamps(&lq.take()), vec![30, 5, 40]);
+
+    let mut lq = queue();
+    assert!(lq.drain_while(|e| e.timestamp > 100).is_empty());
+    assert_eq!(lq.len(), 5);
+
+    assert!(LogQueue::default().drain_while(|_| true).is_empty());
+}
+
+#[test]
+fn drain_all() {
+    let mut lq = queue();
+    let drained = lq.drain_while(|_| true);
+    assert_eq!(timestamps(&drained), timestamps(&queue().take()));
+    assert!(lq.is_empty());
+}
@@ -0,0 +1,92 @@
+// CoralogixLogger dry run mode
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{CoralogixConfig, CoralogixLogger, LogEntry, Logger, Severity};
+
+// Vec<u8> writer that can be read after the logger is boxed
+#[derive(Clone, Default)]
+struct Captured(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// writer that always fails
+struct Broken;
+
+impl std::io::Write for Broken {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("broken"))
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// nothing listens on this port, so any request would fail
+fn logger() -> CoralogixLogger {
+    CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: "http://127.0.0.1:9/api/v1/logs",
+        dry_run: true,
+        ..Default::default()
+    })
+    .expect("init")
+}
+
+#[tokio::test]
+async fn dry_run_writes_json() {
+    let captured = Captured::default();
+    let logger = logger().with_dry_run_writer(Box::new(captured.clone()));
+    let entry = LogEntry {
+        severity: Severity::Warning,
+        text: "hello".to_string(),
+        ..Default::default()
+    };
+    logger.send("sub", vec![entry]).await.expect("send");
+    logger.send("sub", Vec::new()).await.expect("send");
+    logger.health_check().await.expect("health_check");
+    assert_eq!(logger.stats().entries_sent, 1);
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    assert_eq!(output.lines().count(), 1);
+    let payload: serde_json::Value = serde_json::from_str(&output).expect("valid json");
+    assert_eq!(payload["applicationName"], "app");
+    assert_eq!(payload["subsystemName"], "sub");
+    assert_eq!(payload["privateKey"], "key");
+    assert_eq!(payload["logEntries"][0]["text"], "hello");
+    assert_eq!(payload["logEntries"][0]["severity"], 4);
+}
+
+#[tokio::test]
+async fn dry_run_stderr() {
+    logger()
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+}
+
+#[tokio::test]
+async fn dry_run_errors() {
+    let logger = logger().with_dry_run_writer(Box::new(Broken));
+    assert!(logger.send("sub", vec![LogEntry::default()]).await.is_err());
+    assert_eq!(logger.stats().send_errors, 1);
+}
+
+#[tokio::test]
+async fn without_dry_run() {
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: "http://127.0.0.1:9/api/v1/logs",
+        ..Default::default()
+    })
+    .expect("init");
+    assert!(logger.send("sub", vec![LogEntry::default()]).await.is_err());
+}
@@ -0,0 +1,38 @@
+// LogEntry duration_since and age
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{current_time_millis, log, LogEntry, LogQueue, Severity};
+use std::time::Duration;
+
+#[test]
+fn duration_since() {
+    let start = LogEntry::with_timestamp(1_600_000_000_000);
+    let end = LogEntry::with_timestamp(1_600_000_000_100);
+    assert_eq!(end.duration_since(&start), Some(Duration::from_millis(100)));
+    assert_eq!(start.duration_since(&end), None);
+    assert_eq!(start.duration_since(&start), Some(Duration::ZERO));
+}
+
+#[test]
+fn duration_between_log_calls() {
+    let mut lq = LogQueue::default();
+    log!(lq, Severity::Info, text: "start");
+    std::thread::sleep(Duration::from_millis(100));
+    log!(lq, Severity::Info, text: "end");
+    let entries = lq.take();
+    let elapsed = entries[1].duration_since(&entries[0]).expect("duration");
+    assert!(elapsed >= Duration::from_millis(100), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+}
+
+#[test]
+fn age() {
+    let entry = LogEntry::with_timestamp(current_time_millis() - 1000);
+    let age = entry.age();
+    assert!(age >= Duration::from_millis(1000), "{:?}", age);
+    assert!(age < Duration::from_secs(60), "{:?}", age);
+    assert_eq!(
+        LogEntry::with_timestamp(current_time_millis() + 60_000).age(),
+        Duration::ZERO
+    );
+}
@@ -0,0 +1,56 @@
+// Elastic Common Schema serialization
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LogQueue, Severity};
+
+#[test]
+fn ecs_fields() {
+    let entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "disk low".to_string(),
+        category: Some("storage".to_string()),
+        class_name: Some("Disk".to_string()),
+        method_name: Some("check".to_string()),
+        source_file: Some("src/disk.rs".to_string()),
+        source_line: Some(12),
+        ..Default::default()
+    };
+
+    let ecs = entry.to_ecs();
+    assert_eq!(ecs["@timestamp"], "2020-09-13T12:26:40.123Z");
+    assert_eq!(ecs["log"]["level"], "warning");
+    assert_eq!(ecs["message"], "disk low");
+    assert_eq!(ecs["labels"]["category"], "storage");
+    assert_eq!(ecs["log"]["logger"], "Disk");
+    assert_eq!(ecs["log"]["origin"]["function"], "check");
+    assert_eq!(ecs["log"]["origin"]["file"]["name"], "src/disk.rs");
+    assert_eq!(ecs["log"]["origin"]["file"]["line"], 12);
+}
+
+#[test]
+fn ecs_json_lines() {
+    let log_queue = LogQueue::from(vec![
+        LogEntry {
+            timestamp: 0,
+            severity: Severity::Critical,
+            ..Default::default()
+        },
+        LogEntry {
+            timestamp: 951_782_400_000, // leap day
+            severity: Severity::Debug,
+            ..Default::default()
+        },
+    ]);
+
+    let out = log_queue.to_ecs_json_lines();
+    let lines: Vec<serde_json::Value> = out
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("json"))
+        .collect();
+    assert_eq!(lines[0]["@timestamp"], "1970-01-01T00:00:00.000Z");
+    assert_eq!(lines[0]["log"]["level"], "critical");
+    assert!(lines[0].get("labels").is_none());
+    assert_eq!(lines[1]["@timestamp"], "2000-02-29T00:00:00.000Z");
+    assert_eq!(lines[1]["log"]["level"], "debug");
+}
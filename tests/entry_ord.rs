@@ -0,0 +1,90 @@
+// LogEntry ordering by (timestamp, severity), then the remaining fields
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+use std::collections::BTreeSet;
+
+fn entry(timestamp: u64, severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        timestamp,
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn entry_ord() {
+    // same timestamp: ordered by severity, regardless of other fields
+    assert!(entry(10, Severity::Info, "z") < entry(10, Severity::Error, "a"));
+    // different timestamps: ordered by time, regardless of severity
+    assert!(entry(9, Severity::Critical, "a") < entry(10, Severity::Debug, "b"));
+    // same timestamp and severity: ordered by the remaining fields
+    assert!(entry(10, Severity::Info, "a") < entry(10, Severity::Info, "b"));
+
+    let mut v = vec![
+        entry(20, Severity::Debug, "d"),
+        entry(10, Severity::Error, "c"),
+        entry(10, Severity::Info, "a"),
+        entry(5, Severity::Critical, "first"),
+    ];
+    v.push(entry(10, Severity::Warning, "b"));
+    v.sort();
+    let texts: Vec<&str> = v.iter().map(|e| e.text.as_str()).collect();
+    assert_eq!(texts, vec!["first", "a", "b", "c", "d"]);
+}
+
+#[test]
+fn entry_eq() {
+    assert_eq!(
+        entry(10, Severity::Info, "a"),
+        entry(10, Severity::Info, "a")
+    );
+    // all fields are compared, not just timestamp and severity
+    assert_ne!(
+        entry(10, Severity::Info, "a"),
+        entry(10, Severity::Info, "b")
+    );
+    let mut tagged = entry(10, Severity::Info, "a");
+    tagged.add_tag("t");
+    assert_ne!(tagged, entry(10, Severity::Info, "a"));
+
+    let mut m1 = entry(10, Severity::Info, "a");
+    m1.add_metric("bytes", 1.0);
+    let mut m2 = entry(10, Severity::Info, "a");
+    m2.add_metric("bytes", 2.0);
+    assert!(m1 < m2);
+    let mut nan = entry(10, Severity::Info, "a");
+    nan.add_metric("bytes", f64::NAN);
+    assert_eq!(nan, nan.clone());
+}
+
+#[test]
+fn entry_btreeset() {
+    let set: BTreeSet<LogEntry> = vec![
+        entry(20, Severity::Debug, "c"),
+        entry(10, Severity::Error, "b"),
+        entry(10, Severity::Info, "a"),
+        // same timestamp and severity, different text: kept
+        entry(10, Severity::Info, "a2"),
+        // duplicate: removed
+        entry(10, Severity::Info, "a"),
+    ]
+    .into_iter()
+    .collect();
+    let texts: Vec<&str> = set.iter().map(|e| e.text.as_str()).collect();
+    assert_eq!(texts, vec!["a", "a2", "b", "c"]);
+}
+
+#[test]
+fn sort_by_timestamp() {
+    let mut lq = LogQueue::from(vec![
+        entry(20, Severity::Debug, "d"),
+        entry(10, Severity::Error, "c"),
+        entry(10, Severity::Info, "a"),
+        entry(5, Severity::Critical, "first"),
+    ]);
+    lq.sort_by_timestamp();
+    assert!(lq.is_sorted());
+    let texts: Vec<String> = lq.take().into_iter().map(|e| e.text).collect();
+    assert_eq!(texts, vec!["first", "a", "c", "d"]);
+}
@@ -0,0 +1,137 @@
+// ServiceLoggingError
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{
+    ConfigError, CoralogixConfig, CoralogixLogger, LogEntry, Logger, MockLogger,
+    ServiceLoggingError,
+};
+
+async fn coralogix_send(url: &str) -> Result<(), ServiceLoggingError> {
+    let endpoint = format!("{}/api/v1/logs", url);
+    let logger = CoralogixLogger::init(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init");
+    logger.send("sub", vec![LogEntry::default()]).await
+}
+
+#[tokio::test]
+async fn error_authentication() {
+    for status in [401u16, 403].iter() {
+        let server = MockServer::with_statuses(vec![*status]);
+        match coralogix_send(&server.url).await {
+            Err(ServiceLoggingError::AuthenticationError(msg)) => {
+                assert!(msg.contains(&status.to_string()), "{}", msg)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn error_backend() {
+    let server = MockServer::with_statuses(vec![500]);
+    match coralogix_send(&server.url).await {
+        Err(ServiceLoggingError::BackendError { status, body, .. }) => {
+            assert_eq!(status, 500);
+            assert_eq!(body, "status 500");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn error_payload_too_large() {
+    let server = MockServer::with_statuses(vec![413]);
+    let err = coralogix_send(&server.url).await.unwrap_err();
+    let sent = server.requests()[0].body_string().len();
+    match err {
+        ServiceLoggingError::PayloadTooLarge {
+            size,
+            limit,
+            request_id,
+        } => {
+            assert_eq!(size, sent);
+            // Coralogix doesn't report its limit
+            assert_eq!(limit, 0);
+            assert_eq!(request_id, Some(1));
+        }
+        ref other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "Coralogix send failed [request_id=1]: Payload Too Large: {} bytes",
+            sent
+        )
+    );
+}
+
+#[tokio::test]
+async fn error_network() {
+    // bind and drop a listener to get a port with nothing listening
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    match coralogix_send(&format!("http://127.0.0.1:{}", port)).await {
+        Err(ServiceLoggingError::NetworkError(_)) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn error_mock_logger() {
+    let mock = MockLogger::new();
+    mock.fail_next(1);
+    let err = mock.send("sub", Vec::new()).await.unwrap_err();
+    assert!(matches!(
+        err,
+        ServiceLoggingError::BackendError { status: 503, .. }
+    ));
+    assert!(err.to_string().contains("status:503"));
+}
+
+#[test]
+fn error_from_conversions() {
+    let json_err = serde_json::from_str::<LogEntry>("{").unwrap_err();
+    let err = ServiceLoggingError::from(json_err);
+    assert!(matches!(err, ServiceLoggingError::SerializationError(_)));
+    assert!(std::error::Error::source(&err).is_some());
+
+    let err = ServiceLoggingError::from(ConfigError::MissingVar("X".to_string()));
+    match err {
+        ServiceLoggingError::ConfigError(msg) => assert!(msg.contains('X')),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let err = ServiceLoggingError::PayloadTooLarge {
+        size: 2000,
+        limit: 1000,
+        request_id: None,
+    };
+    assert!(matches!(
+        err,
+        ServiceLoggingError::PayloadTooLarge {
+            size: 2000,
+            limit: 1000,
+            ..
+        }
+    ));
+    assert_eq!(
+        err.to_string(),
+        "Payload Too Large: 2000 bytes exceeds limit of 1000"
+    );
+    let err = ServiceLoggingError::PayloadTooLarge {
+        size: 0,
+        limit: 0,
+        request_id: None,
+    };
+    assert_eq!(err.to_string(), "Payload Too Large");
+}
@@ -1,5 +1,6 @@
 // Example use of ConsoleLogger in non-wasm32 builds.
 //
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
 #[cfg(not(target_arch = "wasm32"))]
 use service_logging::{log, ConsoleLogger, LogQueue, Severity};
 
@@ -0,0 +1,55 @@
+// LogEntry::to_serde_value and field_value
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use serde_json::json;
+use service_logging::{LogEntry, Severity};
+
+fn entry() -> LogEntry {
+    let mut entry = LogEntry {
+        timestamp: 1234,
+        severity: Severity::Error,
+        text: "failed".to_string(),
+        class_name: Some("Db".to_string()),
+        source_line: Some(7),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("user".into(), "bob".into());
+    // standard fields take precedence
+    entry.custom_fields.insert("text".into(), "custom".into());
+    entry.add_metric("duration_ms", 2.5);
+    entry
+}
+
+#[test]
+fn to_serde_value() {
+    assert_eq!(
+        entry().to_serde_value(),
+        json!({
+            "timestamp": 1234,
+            "severity": 5,
+            "text": "failed",
+            "className": "Db",
+            "sourceLine": 7,
+            "user": "bob",
+            "metrics": {"duration_ms": 2.5},
+        })
+    );
+}
+
+#[test]
+fn field_value() {
+    let e = entry();
+    assert_eq!(e.field_value("severity"), Some(json!(5)));
+    assert!(e.field_value("severity").unwrap().is_u64());
+    assert_eq!(e.field_value("text"), Some(json!("failed")));
+    assert!(e.field_value("text").unwrap().is_string());
+    assert_eq!(e.field_value("timestamp"), Some(json!(1234)));
+    assert_eq!(e.field_value("class_name"), Some(json!("Db")));
+    assert_eq!(e.field_value("className"), Some(json!("Db")));
+    assert_eq!(e.field_value("source_line"), Some(json!(7)));
+    assert_eq!(e.field_value("user"), Some(json!("bob")));
+    assert_eq!(e.field_value("metrics"), Some(json!({"duration_ms": 2.5})));
+    assert_eq!(e.field_value("category"), None);
+    assert_eq!(e.field_value("custom_fields"), None);
+    assert_eq!(e.field_value("missing"), None);
+}
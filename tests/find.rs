@@ -0,0 +1,47 @@
+// LogQueue::find, find_all, and position
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn queue() -> LogQueue {
+    LogQueue::from(
+        (0..10)
+            .map(|n| LogEntry {
+                severity: if n % 3 == 0 {
+                    Severity::Error
+                } else {
+                    Severity::Info
+                },
+                text: n.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn find() {
+    assert!(LogQueue::default().find(|_| true).is_none());
+    assert_eq!(LogQueue::default().position(|_| true), None);
+
+    let lq = queue();
+    assert_eq!(
+        lq.find(|e| e.text == "7").map(|e| e.text.as_str()),
+        Some("7")
+    );
+    assert!(lq.find(|e| e.text == "missing").is_none());
+    assert_eq!(lq.position(|e| e.text == "7"), Some(7));
+    assert_eq!(lq.position(|e| e.severity == Severity::Info), Some(1));
+    assert_eq!(lq.len(), 10);
+}
+
+#[test]
+fn find_all() {
+    let lq = queue();
+    let texts: Vec<&str> = lq
+        .find_all(|e| e.severity == Severity::Error)
+        .iter()
+        .map(|e| e.text.as_str())
+        .collect();
+    assert_eq!(texts, vec!["0", "3", "6", "9"]);
+    assert!(lq.find_all(|_| false).is_empty());
+}
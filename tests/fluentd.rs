@@ -0,0 +1,75 @@
+// FluentdLogger
+//
+#![cfg(feature = "fluentd")]
+mod common;
+use common::MockServer;
+use service_logging::{FluentdConfig, FluentdLogger, LogEntry, Severity};
+
+fn entries() -> Vec<LogEntry> {
+    vec![
+        LogEntry {
+            timestamp: 1_600_000_000_500,
+            severity: Severity::Info,
+            text: "started".to_string(),
+            ..Default::default()
+        },
+        LogEntry {
+            timestamp: 1_600_000_001_000,
+            severity: Severity::Error,
+            text: "failed".to_string(),
+            ..Default::default()
+        },
+    ]
+}
+
+#[tokio::test]
+async fn fluentd_send() {
+    let server = MockServer::start();
+    let logger = FluentdLogger::init(FluentdConfig {
+        endpoint: format!("{}/", server.url),
+        tag: "app.web".to_string(),
+    })
+    .expect("init");
+    logger.send("web", entries()).await.expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let req = &requests[0];
+    assert_eq!(req.method, "POST");
+    assert_eq!(req.path, "/app.web");
+
+    #[cfg(feature = "msgpack")]
+    {
+        assert_eq!(req.header("content-type"), Some("application/msgpack"));
+        let tuples: Vec<(u64, serde_json::Value)> =
+            rmp_serde::from_slice(&req.body).expect("msgpack body");
+        assert_eq!(tuples.len(), 2);
+        assert_eq!(tuples[0].0, 1_600_000_000);
+        assert_eq!(tuples[0].1["text"], "started");
+        assert_eq!(tuples[0].1["subsystem"], "web");
+        assert_eq!(tuples[1].1["severity"], 5);
+    }
+    #[cfg(not(feature = "msgpack"))]
+    {
+        assert_eq!(req.header("content-type"), Some("application/json"));
+        let records = req.json();
+        let records = records.as_array().expect("json array");
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["time"], 1_600_000_000.5);
+        assert_eq!(records[0]["text"], "started");
+        assert_eq!(records[0]["subsystem"], "web");
+        assert_eq!(records[1]["severity"], 5);
+    }
+}
+
+#[tokio::test]
+async fn fluentd_empty() {
+    let server = MockServer::start();
+    let logger = FluentdLogger::init(FluentdConfig {
+        endpoint: server.url.clone(),
+        tag: "app".to_string(),
+    })
+    .expect("init");
+    logger.send("web", Vec::new()).await.expect("send");
+    assert!(server.requests().is_empty());
+}
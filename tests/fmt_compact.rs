@@ -0,0 +1,64 @@
+// LogEntry::fmt_compact, LowerHex, and ConsoleLogger field output
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{ConsoleLogger, ConsoleLoggerConfig, LogEntry, Severity};
+use std::fmt;
+
+struct Compact<'a>(&'a LogEntry);
+
+impl fmt::Display for Compact<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_compact(f)
+    }
+}
+
+fn entry() -> LogEntry {
+    LogEntry {
+        timestamp: 1_700_000_000_000,
+        severity: Severity::Warning,
+        text: "login failed".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn compact_fields() {
+    let mut e = entry();
+    assert_eq!(
+        Compact(&e).to_string(),
+        r#"1700000000000 Warning text="login failed""#
+    );
+    assert!(!Compact(&e).to_string().contains("category"));
+
+    e.category = Some("auth".to_string());
+    e.class_name = Some("AuthService".to_string());
+    e.custom_fields.insert("user".into(), "bob".into());
+    e.add_tag("security");
+    assert_eq!(
+        Compact(&e).to_string(),
+        r#"1700000000000 Warning text="login failed" category=auth class_name=AuthService tags=security user=bob"#
+    );
+}
+
+#[test]
+fn console_includes_fields() {
+    let logger = ConsoleLogger::new(ConsoleLoggerConfig::default());
+    let mut e = entry();
+    assert_eq!(
+        logger.format_entry("api", &e),
+        "1700000000000 api Warning login failed"
+    );
+    e.category = Some("auth".to_string());
+    assert_eq!(
+        logger.format_entry("api", &e),
+        "1700000000000 api Warning login failed category=auth"
+    );
+}
+
+#[test]
+fn lower_hex_gelf() {
+    let gelf: serde_json::Value = serde_json::from_str(&format!("{:x}", entry())).unwrap();
+    assert_eq!(gelf["version"], "1.1");
+    assert_eq!(gelf["short_message"], "login failed");
+    assert_eq!(gelf, entry().to_gelf("-"));
+}
@@ -0,0 +1,51 @@
+// GELF serialization
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{log, LogEntry, LogQueue, Severity};
+
+#[test]
+fn gelf_fields() {
+    let mut entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "disk low".to_string(),
+        category: Some("storage".to_string()),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("free mb".into(), "10".into());
+    entry.custom_fields.insert("id".into(), "reserved".into());
+
+    let gelf = entry.to_gelf("host1");
+    assert_eq!(gelf["version"], "1.1");
+    assert_eq!(gelf["host"], "host1");
+    assert_eq!(gelf["short_message"], "disk low");
+    assert!(gelf["timestamp"].is_f64());
+    assert_eq!(gelf["timestamp"].as_f64(), Some(1_600_000_000.123));
+    assert_eq!(gelf["level"], 4);
+    assert_eq!(gelf["_category"], "storage");
+    assert_eq!(gelf["_free_mb"], "10");
+    assert!(gelf.get("_id").is_none());
+    assert!(gelf.get("_class_name").is_none());
+}
+
+#[test]
+fn gelf_lines() {
+    let mut log_queue = LogQueue::default();
+    log!(log_queue, Severity::Info, text: "one", user: "alice");
+    log!(log_queue, Severity::Error, text: "two");
+
+    let out = log_queue.to_gelf_lines("host1");
+    let lines: Vec<serde_json::Value> = out
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("json"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["short_message"], "one");
+    assert_eq!(lines[0]["_user"], "alice");
+    assert_eq!(lines[1]["level"], 3);
+    for l in lines.iter() {
+        assert!(l["version"].is_string());
+        assert!(l["host"].is_string());
+        assert!(l["timestamp"].is_f64());
+    }
+}
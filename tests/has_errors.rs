@@ -0,0 +1,51 @@
+// LogQueue::has_errors, has_critical, error_entries, and max_severity
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn queue(severities: &[Severity]) -> LogQueue {
+    LogQueue::from(
+        severities
+            .iter()
+            .enumerate()
+            .map(|(n, s)| LogEntry {
+                severity: s.clone(),
+                text: n.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn all_debug() {
+    let lq = queue(&vec![Severity::Debug; 3]);
+    assert!(!lq.has_errors());
+    assert!(!lq.has_critical());
+    assert!(lq.error_entries().is_empty());
+    assert_eq!(lq.max_severity(), Some(Severity::Debug));
+    assert_eq!(LogQueue::default().max_severity(), None);
+}
+
+#[test]
+fn mixed() {
+    let lq = queue(&[
+        Severity::Info,
+        Severity::Error,
+        Severity::Warning,
+        Severity::Error,
+    ]);
+    assert!(lq.has_errors());
+    assert!(!lq.has_critical());
+    let texts: Vec<&str> = lq.error_entries().iter().map(|e| e.text.as_str()).collect();
+    assert_eq!(texts, vec!["1", "3"]);
+    assert_eq!(lq.max_severity(), Some(Severity::Error));
+    assert_eq!(lq.len(), 4);
+}
+
+#[test]
+fn all_critical() {
+    let lq = queue(&vec![Severity::Critical; 2]);
+    assert!(lq.has_errors());
+    assert!(lq.has_critical());
+    assert_eq!(lq.error_entries().len(), 2);
+}
@@ -0,0 +1,63 @@
+// Logger::health_check
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{
+    silent_logger, ConsoleLogger, CoralogixConfig, CoralogixLogger, Severity, VecLogger,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn coralogix_health_check() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::init(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init");
+
+    let elapsed = logger.health_check().await.expect("health check");
+    assert!(elapsed >= Duration::ZERO);
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/api/v1/logs");
+    assert_eq!(requests[0].json()["logEntries"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn coralogix_health_check_error() {
+    let server = MockServer::with_statuses(vec![403]);
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::init(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init");
+    let err = logger.health_check().await.expect_err("forbidden");
+    assert!(err.to_string().contains("403"));
+}
+
+#[tokio::test]
+async fn default_health_check() {
+    assert_eq!(
+        ConsoleLogger::init().health_check().await.unwrap(),
+        Duration::ZERO
+    );
+    assert_eq!(
+        silent_logger().health_check().await.unwrap(),
+        Duration::ZERO
+    );
+
+    let (logger, handle) = VecLogger::new();
+    assert!(logger.health_check().await.unwrap() >= Duration::ZERO);
+    assert!(
+        handle.has_entry_matching(|e| e.severity == Severity::Debug && e.text == "health check")
+    );
+}
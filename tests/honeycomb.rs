@@ -0,0 +1,47 @@
+// HoneycombLogger
+//
+#![cfg(feature = "honeycomb")]
+mod common;
+use common::MockServer;
+use service_logging::{HoneycombConfig, HoneycombLogger, LogEntry, Severity};
+
+#[tokio::test]
+async fn honeycomb_send() {
+    let server = MockServer::start();
+    let logger = HoneycombLogger::init(HoneycombConfig {
+        api_key: "secret".to_string(),
+        dataset: "web-prod".to_string(),
+        endpoint: server.url.clone(),
+    })
+    .expect("init");
+
+    let mut entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "slow request".to_string(),
+        category: Some("http".to_string()),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("path".into(), "/api".into());
+    entry.add_metric("duration_ms", 1500.0);
+    logger.send("web", vec![entry]).await.expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let req = &requests[0];
+    assert_eq!(req.path, "/1/batch/web-prod");
+    assert_eq!(req.header("x-honeycomb-team"), Some("secret"));
+
+    let events = req.json();
+    let events = events.as_array().expect("json array");
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["time"], "2020-09-13T12:26:40.123Z");
+    let data = &events[0]["data"];
+    assert_eq!(data["text"], "slow request");
+    assert_eq!(data["severity"], 4);
+    assert_eq!(data["category"], "http");
+    assert_eq!(data["subsystem"], "web");
+    assert_eq!(data["path"], "/api");
+    assert_eq!(data["duration_ms"], 1500.0);
+    assert!(data.get("customFields").is_none());
+}
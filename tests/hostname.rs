@@ -0,0 +1,77 @@
+// LogEntry::hostname and CoralogixConfig::include_hostname
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixConfig, CoralogixLogger, LogEntry, Logger};
+
+// host name reported by the system
+fn system_hostname() -> String {
+    let out = std::process::Command::new("hostname")
+        .output()
+        .expect("hostname command");
+    String::from_utf8(out.stdout).unwrap().trim().to_string()
+}
+
+#[test]
+fn hostname_serialized() {
+    let entry = LogEntry {
+        hostname: Some("web-1".to_string()),
+        ..Default::default()
+    };
+    let val = serde_json::to_value(&entry).unwrap();
+    assert_eq!(val["hostname"], "web-1");
+
+    let val = serde_json::to_value(LogEntry::default()).unwrap();
+    assert!(val.get("hostname").is_none());
+}
+
+#[test]
+fn with_hostname() {
+    let entry = LogEntry::with_hostname();
+    assert_eq!(entry.hostname, Some(system_hostname()));
+}
+
+#[tokio::test]
+async fn include_hostname() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        include_hostname: true,
+        ..Default::default()
+    })
+    .expect("init");
+    let preset = LogEntry {
+        hostname: Some("other".to_string()),
+        ..Default::default()
+    };
+    logger
+        .send("sub", vec![LogEntry::default(), preset])
+        .await
+        .expect("send");
+    let body = server.requests()[0].json();
+    assert_eq!(body["logEntries"][0]["hostname"], system_hostname());
+    assert_eq!(body["logEntries"][1]["hostname"], "other");
+}
+
+#[tokio::test]
+async fn hostname_not_included_by_default() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init");
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    let body = server.requests()[0].json();
+    assert!(body["logEntries"][0].get("hostname").is_none());
+}
@@ -0,0 +1,50 @@
+// LogQueue::insert_sorted
+//
+use service_logging::{LogEntry, LogQueue};
+
+fn entry(timestamp: u64, text: &str) -> LogEntry {
+    LogEntry {
+        timestamp,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn insert_sorted_random() {
+    let mut log_queue = LogQueue::new();
+    // linear congruential generator, for repeatable pseudo-random timestamps
+    let mut state = 42u64;
+    let mut expected = Vec::new();
+    for n in 0..100 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let timestamp = (state >> 33) % 50;
+        expected.push(n.to_string());
+        log_queue.insert_sorted(entry(timestamp, &n.to_string()));
+        assert!(log_queue.is_sorted());
+    }
+    assert_eq!(log_queue.len(), 100);
+    let mut texts: Vec<String> = log_queue.take().into_iter().map(|e| e.text).collect();
+    texts.sort();
+    expected.sort();
+    assert_eq!(texts, expected);
+}
+
+#[test]
+fn insert_sorted_stable() {
+    let mut log_queue = LogQueue::new();
+    log_queue.insert_sorted(entry(2, "a"));
+    log_queue.insert_sorted(entry(1, "b"));
+    log_queue.insert_sorted(entry(2, "c"));
+    log_queue.insert_sorted(entry(3, "d"));
+    let texts: Vec<String> = log_queue.take().into_iter().map(|e| e.text).collect();
+    assert_eq!(texts, vec!["b", "a", "c", "d"]);
+}
+
+#[test]
+fn is_sorted() {
+    assert!(LogQueue::new().is_sorted());
+    assert!(!LogQueue::from(vec![entry(2, "a"), entry(1, "b")]).is_sorted());
+}
@@ -0,0 +1,30 @@
+// LogQueue::get and iter_with_index
+//
+use service_logging::{LogEntry, LogQueue};
+
+fn queue() -> LogQueue {
+    LogQueue::from((0..5).map(LogEntry::with_timestamp).collect())
+}
+
+#[test]
+fn get() {
+    let mut lq = queue();
+    assert_eq!(lq.get(0).map(|e| e.timestamp), Some(0));
+    assert_eq!(lq.get(4).map(|e| e.timestamp), Some(4));
+    assert!(lq.get(5).is_none());
+    assert!(LogQueue::default().get(0).is_none());
+
+    lq.push_front(LogEntry::with_timestamp(100));
+    assert_eq!(lq.get(0).map(|e| e.timestamp), Some(100));
+}
+
+#[test]
+fn iter_with_index() {
+    let lq = queue();
+    assert_eq!(lq.iter_with_index().count(), lq.len());
+    for (n, e) in lq.iter_with_index() {
+        assert_eq!(e.timestamp, n as u64);
+        assert_eq!(lq.get(n), Some(e));
+    }
+    assert_eq!(LogQueue::default().iter_with_index().count(), 0);
+}
@@ -0,0 +1,145 @@
+// json and json-lines serialization
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn full_entry() -> LogEntry {
+    let mut entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Error,
+        text: "text".to_string(),
+        category: Some("category".to_string()),
+        class_name: Some("class".to_string()),
+        method_name: Some("method".to_string()),
+        thread_id: Some("thread".to_string()),
+        source_file: Some("file.rs".to_string()),
+        source_line: Some(7),
+        source_module: Some("module".to_string()),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("key".into(), "value".into());
+    entry.timestamp_us = Some(1_600_000_000_123_456);
+    entry.add_tag("tag");
+    entry.add_metric("duration_ms", 12.5);
+    entry.environment = Some("staging".to_string());
+    entry.app_version = Some("1.2.3".to_string());
+    entry.with_trace("trace", "span")
+}
+
+#[test]
+fn entry_to_json() {
+    let json = LogEntry {
+        timestamp: 1,
+        severity: Severity::Info,
+        text: "hello".to_string(),
+        ..Default::default()
+    }
+    .to_json()
+    .expect("json");
+    assert_eq!(json, r#"{"timestamp":1,"severity":3,"text":"hello"}"#);
+}
+
+#[test]
+fn json_lines_round_trip() {
+    let log_queue = LogQueue::from(vec![full_entry(), LogEntry::default(), full_entry()]);
+    let lines = log_queue.to_json_lines().expect("json");
+    assert_eq!(lines.lines().count(), 3);
+
+    let mut copy = LogQueue::from_json_lines(&lines).expect("parse");
+    assert_eq!(copy.to_json_lines().expect("json"), lines);
+
+    let entries = copy.take();
+    let e = &entries[0];
+    assert_eq!(e.timestamp, 1_600_000_000_123);
+    assert_eq!(e.severity, Severity::Error);
+    assert_eq!(e.text, "text");
+    assert_eq!(e.category.as_deref(), Some("category"));
+    assert_eq!(e.class_name.as_deref(), Some("class"));
+    assert_eq!(e.method_name.as_deref(), Some("method"));
+    assert_eq!(e.thread_id.as_deref(), Some("thread"));
+    assert_eq!(e.source_file.as_deref(), Some("file.rs"));
+    assert_eq!(e.source_line, Some(7));
+    assert_eq!(e.source_module.as_deref(), Some("module"));
+    assert_eq!(
+        e.custom_fields.get("key").map(|s| s.as_str()),
+        Some("value")
+    );
+    assert_eq!(e.timestamp_us, Some(1_600_000_000_123_456));
+    assert_eq!(e.tags, vec!["tag".to_string()]);
+    assert_eq!(e.metrics.get("duration_ms"), Some(&12.5));
+    assert_eq!(e.environment.as_deref(), Some("staging"));
+    assert_eq!(e.app_version.as_deref(), Some("1.2.3"));
+    assert_eq!(e.trace_id.as_deref(), Some("trace"));
+    assert_eq!(e.span_id.as_deref(), Some("span"));
+    assert!(entries[1].category.is_none());
+    assert!(entries[1].custom_fields.is_empty());
+}
+
+#[test]
+fn json_lines_errors() {
+    assert!(LogQueue::from_json_lines("\n\n").expect("empty").is_empty());
+    assert!(LogQueue::from_json_lines("{not json}").is_err());
+
+    let good = full_entry().to_json().unwrap();
+    let input = format!("{}\n\n{{bad\n{}\n[1]\n", good, good);
+    let errors = LogQueue::from_json_lines(&input).expect_err("errors");
+    let lines: Vec<usize> = errors.iter().map(|(n, _)| *n).collect();
+    assert_eq!(lines, vec![3, 5]);
+}
+
+#[test]
+fn from_file() {
+    let path = std::env::temp_dir().join(format!("service-logging-{}.jsonl", std::process::id()));
+    let log_queue = LogQueue::from((0..1000).map(LogEntry::with_timestamp).collect::<Vec<_>>());
+    std::fs::write(&path, log_queue.to_json_lines().unwrap()).unwrap();
+
+    let mut copy = LogQueue::from_file(&path).expect("from_file");
+    assert_eq!(copy.len(), 1000);
+    assert_eq!(copy.take()[999].timestamp, 999);
+
+    // a small buffer: lines are processed as they are read
+    let file = std::fs::File::open(&path).unwrap();
+    let copy = LogQueue::from_reader(std::io::BufReader::with_capacity(16, file)).unwrap();
+    assert_eq!(copy.len(), 1000);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(LogQueue::from_file(&path).is_err());
+    assert!(LogQueue::from_reader("{bad\n".as_bytes()).is_err());
+    assert!(LogQueue::from_reader("\n\n".as_bytes()).unwrap().is_empty());
+}
+
+#[test]
+fn write_to_writer() {
+    let log_queue = LogQueue::from(vec![full_entry(), LogEntry::default(), full_entry()]);
+    let mut buf: Vec<u8> = Vec::new();
+    log_queue.write_to_writer(&mut buf).expect("write");
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        log_queue.to_json_lines().unwrap()
+    );
+
+    let mut buf: Vec<u8> = Vec::new();
+    LogQueue::default()
+        .write_to_writer(&mut buf)
+        .expect("write");
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn write_to_file() {
+    let path = std::env::temp_dir().join(format!("service-logging-w{}.jsonl", std::process::id()));
+    let log_queue = LogQueue::from((0..100).map(LogEntry::with_timestamp).collect::<Vec<_>>());
+    let file = std::fs::File::create(&path).unwrap();
+    log_queue
+        .write_to_writer(std::io::BufWriter::new(file))
+        .expect("write");
+    let copy = LogQueue::from_file(&path).expect("read");
+    assert_eq!(
+        copy.to_json_lines().unwrap(),
+        log_queue.to_json_lines().unwrap()
+    );
+
+    log_queue.write_to_file(&path).expect("write_to_file");
+    assert_eq!(LogQueue::from_file(&path).unwrap().len(), 100);
+    std::fs::remove_file(&path).unwrap();
+}
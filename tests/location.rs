@@ -0,0 +1,16 @@
+// log! records the call site when the "location" feature is enabled
+//
+#![cfg(feature = "location")]
+use service_logging::{log, LogQueue, Severity};
+
+#[test]
+fn log_captures_location() {
+    let mut log_queue = LogQueue::default();
+    let line = line!() + 1;
+    log!(log_queue, Severity::Info, one: "Thing One");
+
+    let entries = log_queue.take();
+    assert_eq!(entries[0].source_file.as_deref(), Some(file!()));
+    assert_eq!(entries[0].source_line, Some(line));
+    assert_eq!(entries[0].source_module.as_deref(), Some(module_path!()));
+}
@@ -0,0 +1,38 @@
+// thread-local log context merged into log! fields
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{log, log_context, LogQueue, Severity};
+
+#[test]
+fn context_set_and_clear() {
+    let mut log_queue = LogQueue::default();
+    log_context::set("request_id", "r-1");
+    log_context::set("user_id", 7);
+    log!(log_queue, Severity::Info, method: "GET", user_id: 8);
+    log_context::clear();
+    log!(log_queue, Severity::Info, method: "PUT");
+
+    let entries = log_queue.take();
+    // explicit value takes precedence over context
+    assert_eq!(
+        entries[0].text,
+        r#"{"method":"GET","request_id":"r-1","user_id":"8"}"#
+    );
+    assert_eq!(entries[1].text, r#"{"method":"PUT"}"#);
+}
+
+#[test]
+fn context_does_not_leak_between_requests() {
+    let mut log_queue = LogQueue::default();
+    for id in 1..=2 {
+        log_context::with_context(vec![("request_id", id)], || {
+            log!(log_queue, Severity::Info, handler: "index");
+        });
+    }
+    log!(log_queue, Severity::Info, handler: "none");
+
+    let entries = log_queue.take();
+    assert_eq!(entries[0].text, r#"{"handler":"index","request_id":"1"}"#);
+    assert_eq!(entries[1].text, r#"{"handler":"index","request_id":"2"}"#);
+    assert_eq!(entries[2].text, r#"{"handler":"none"}"#);
+}
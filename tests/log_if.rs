@@ -0,0 +1,46 @@
+// conditional logging with log_if! and log_if_severity!
+//
+use service_logging::{log_if, log_if_severity, LogQueue, Severity};
+use std::cell::Cell;
+
+#[test]
+fn log_if_condition() {
+    let mut log_queue = LogQueue::default();
+    let evaluated = Cell::new(0);
+    let value = || {
+        evaluated.set(evaluated.get() + 1);
+        "x"
+    };
+
+    log_if!(false, log_queue, Severity::Info, one: value());
+    assert!(log_queue.is_empty());
+    assert_eq!(evaluated.get(), 0);
+
+    log_if!(true, log_queue, Severity::Info, one: value());
+    assert_eq!(evaluated.get(), 1);
+    let entries = log_queue.take();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].text, r#"{"one":"x"}"#);
+}
+
+#[test]
+fn log_if_severity_threshold() {
+    let mut log_queue = LogQueue::default();
+    let evaluated = Cell::new(0);
+    let value = || {
+        evaluated.set(evaluated.get() + 1);
+        "x"
+    };
+
+    log_if_severity!(Severity::Warning, log_queue, Severity::Info, one: value());
+    assert!(log_queue.is_empty());
+    assert_eq!(evaluated.get(), 0);
+
+    log_if_severity!(Severity::Warning, log_queue, Severity::Warning, one: value());
+    log_if_severity!(Severity::Warning, log_queue, Severity::Error, one: value());
+    assert_eq!(evaluated.get(), 2);
+    let entries = log_queue.take();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].severity, Severity::Warning);
+    assert_eq!(entries[1].severity, Severity::Error);
+}
@@ -0,0 +1,25 @@
+// log! keys may be identifiers or string literals
+//
+use service_logging::{log, LogQueue, Severity};
+
+#[test]
+fn string_literal_keys() {
+    let mut log_queue = LogQueue::default();
+    log!(log_queue, Severity::Info, "x-forwarded-for": "10.0.0.1", "content-type": "text/html", status: 200);
+
+    let entries = log_queue.take();
+    assert_eq!(
+        entries[0].text,
+        r#"{"content-type":"text/html","status":"200","x-forwarded-for":"10.0.0.1"}"#
+    );
+}
+
+#[test]
+fn string_literal_special_key() {
+    let mut log_queue = LogQueue::default();
+    log!(log_queue, Severity::Info, "text": "hello", "category": "http");
+
+    let entries = log_queue.take();
+    assert_eq!(entries[0].text, "hello");
+    assert_eq!(entries[0].category.as_deref(), Some("http"));
+}
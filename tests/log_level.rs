@@ -0,0 +1,46 @@
+// LogLevel newtype
+//
+use service_logging::{is_enabled, LogLevel, Severity};
+
+#[test]
+fn conversions() {
+    let level = LogLevel::from_severity(Severity::Warning);
+    assert_eq!(level.clone().into_severity(), Severity::Warning);
+    assert_eq!(
+        LogLevel::from(Severity::Error),
+        LogLevel::from_severity(Severity::Error)
+    );
+    assert_eq!(Severity::from(level.clone()), Severity::Warning);
+    assert_eq!(level.to_string(), "Warning");
+    assert_eq!("CRITICAL".parse::<LogLevel>().unwrap(), Severity::Critical);
+    assert!("loud".parse::<LogLevel>().is_err());
+    assert_eq!(LogLevel::default(), Severity::Info);
+}
+
+#[test]
+fn comparisons() {
+    let warning = LogLevel::from_severity(Severity::Warning);
+    let error = LogLevel::from_severity(Severity::Error);
+    assert!(warning < error);
+    assert!(warning > Severity::Info);
+    assert!(warning == Severity::Warning);
+    assert!(Severity::Error > warning);
+    assert!(Severity::Debug < warning);
+}
+
+#[test]
+fn enabled() {
+    let level = LogLevel::from_severity(Severity::Warning);
+    assert!(is_enabled(level.clone(), Severity::Warning));
+    assert!(is_enabled(level.clone(), Severity::Critical));
+    assert!(!is_enabled(level.clone(), Severity::Info));
+    assert!(!level.is_enabled(&Severity::Debug));
+}
+
+#[test]
+fn serde() {
+    let level = LogLevel::from_severity(Severity::Error);
+    assert_eq!(serde_json::to_string(&level).unwrap(), "5");
+    let parsed: LogLevel = serde_json::from_str("\"warning\"").unwrap();
+    assert_eq!(parsed, Severity::Warning);
+}
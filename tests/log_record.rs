@@ -0,0 +1,54 @@
+// conversion from log::Record (feature "log")
+//
+#![cfg(feature = "log")]
+use service_logging::{LogEntry, Severity};
+
+#[test]
+fn from_record() {
+    let entry = LogEntry::from(
+        &log::Record::builder()
+            .args(format_args!("connected to {}", "db"))
+            .level(log::Level::Warn)
+            .target("app::db")
+            .module_path(Some("app::db::pool"))
+            .file(Some("src/db/pool.rs"))
+            .line(Some(42))
+            .build(),
+    );
+    assert_eq!(entry.severity, Severity::Warning);
+    assert_eq!(entry.text, "connected to db");
+    assert_eq!(entry.class_name.as_deref(), Some("app::db"));
+    assert_eq!(entry.method_name.as_deref(), Some("app::db::pool"));
+    assert_eq!(entry.source_file.as_deref(), Some("src/db/pool.rs"));
+    assert_eq!(entry.source_line, Some(42));
+    assert!(entry.timestamp > 0);
+}
+
+#[test]
+fn record_without_location() {
+    let entry = LogEntry::from(
+        &log::Record::builder()
+            .args(format_args!("hello"))
+            .level(log::Level::Info)
+            .build(),
+    );
+    assert_eq!(entry.text, "hello");
+    assert!(entry.method_name.is_none());
+    assert!(entry.source_file.is_none());
+    assert!(entry.source_line.is_none());
+}
+
+#[test]
+fn levels() {
+    assert_eq!(Severity::from_log_level(log::Level::Error), Severity::Error);
+    assert_eq!(
+        Severity::from_log_level(log::Level::Warn),
+        Severity::Warning
+    );
+    assert_eq!(Severity::from_log_level(log::Level::Info), Severity::Info);
+    assert_eq!(
+        Severity::from_log_level(log::Level::Debug),
+        Severity::Verbose
+    );
+    assert_eq!(Severity::from(log::Level::Trace), Severity::Debug);
+}
@@ -0,0 +1,34 @@
+// logf! builds the text field with format-string syntax
+//
+use service_logging::{logf, LogQueue, Severity};
+
+#[test]
+fn logf_formats_text() {
+    let mut log_queue = LogQueue::default();
+    let user_id = 42;
+    logf!(log_queue, Severity::Info, "user {} logged in", user_id);
+    logf!(log_queue, Severity::Info, "no args");
+    logf!(
+        log_queue,
+        Severity::Warning,
+        "user {user_id} failed {} times",
+        3
+    );
+
+    let entries = log_queue.take();
+    assert_eq!(entries[0].text, "user 42 logged in");
+    assert_eq!(entries[0].severity, Severity::Info);
+    assert_eq!(entries[1].text, "no args");
+    assert_eq!(entries[2].text, "user 42 failed 3 times");
+}
+
+#[test]
+fn logf_special_fields() {
+    let mut log_queue = LogQueue::default();
+    logf!(log_queue, Severity::Info, "{}-{}", "a", "b"; category: "auth", method_name: "login");
+
+    let entries = log_queue.take();
+    assert_eq!(entries[0].text, "a-b");
+    assert_eq!(entries[0].category.as_deref(), Some("auth"));
+    assert_eq!(entries[0].method_name.as_deref(), Some("login"));
+}
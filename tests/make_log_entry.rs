@@ -0,0 +1,23 @@
+// make_log_entry! builds an entry without a queue
+//
+use service_logging::{make_log_entry, LogQueue, Severity};
+
+#[test]
+fn make_entry_fields() {
+    let e = make_log_entry!(Severity::Warning, method: "GET", status: 200, category: "http");
+    assert_eq!(e.severity, Severity::Warning);
+    assert_eq!(e.text, r#"{"method":"GET","status":"200"}"#);
+    assert_eq!(e.category.as_deref(), Some("http"));
+}
+
+#[test]
+fn make_entry_push_to_queues() {
+    let mut q1 = LogQueue::default();
+    let mut q2 = LogQueue::default();
+    let e = make_log_entry!(Severity::Info, text: "hello");
+    q1.log(e);
+    q2.log(make_log_entry!(Severity::Info, text: "hello"));
+
+    assert_eq!(q1.take()[0].text, "hello");
+    assert_eq!(q2.take()[0].text, "hello");
+}
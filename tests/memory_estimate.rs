@@ -0,0 +1,35 @@
+// LogQueue::memory_estimate
+//
+use service_logging::{LogEntry, LogQueue};
+
+#[test]
+fn memory_estimate() {
+    let mut lq = LogQueue::new();
+    assert_eq!(lq.memory_estimate(), 0);
+    assert!(!lq.memory_exceeds(0));
+
+    let text = "x".repeat(1000);
+    lq.log(LogEntry {
+        text: text.clone(),
+        ..Default::default()
+    });
+    let one = lq.memory_estimate();
+    assert!(one >= text.len() + std::mem::size_of::<LogEntry>());
+    assert!(lq.memory_exceeds(1000));
+    assert!(!lq.memory_exceeds(one));
+
+    let mut entry = LogEntry {
+        text: text.clone(),
+        category: Some("category".to_string()),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("key".into(), "value".into());
+    lq.log(entry);
+    assert_eq!(
+        lq.memory_estimate(),
+        2 * one + "category".len() + "key".len() + "value".len()
+    );
+
+    lq.clear();
+    assert_eq!(lq.memory_estimate(), 0);
+}
@@ -0,0 +1,28 @@
+// numeric metric fields
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{make_log_entry, LogEntry, Severity};
+
+#[test]
+fn metrics_from_macro() {
+    let e = make_log_entry!(Severity::Info, method: "GET", status_code: 200, duration_ms: 12.5, count: "many");
+    assert_eq!(e.metrics.get("status_code"), Some(&200.0));
+    assert_eq!(e.metrics.get("duration_ms"), Some(&12.5));
+    // not numeric: stays with other fields
+    assert!(!e.metrics.contains_key("count"));
+    assert_eq!(e.text, r#"{"count":"many","method":"GET"}"#);
+
+    let json: serde_json::Value = serde_json::from_str(&e.to_json().unwrap()).unwrap();
+    assert!(json["metrics"]["status_code"].is_number());
+    assert_eq!(json["metrics"]["status_code"].as_f64(), Some(200.0));
+    assert_eq!(json["metrics"]["duration_ms"].as_f64(), Some(12.5));
+}
+
+#[test]
+fn metrics_builder() {
+    let mut e = LogEntry::default();
+    assert!(!e.to_json().unwrap().contains("metrics"));
+    e.add_metric("bytes", 1024.0);
+    let json: serde_json::Value = serde_json::from_str(&e.to_json().unwrap()).unwrap();
+    assert!(json["metrics"]["bytes"].is_number());
+}
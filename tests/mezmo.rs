@@ -0,0 +1,62 @@
+// MezmoLogger
+//
+#![cfg(feature = "mezmo")]
+mod common;
+use common::MockServer;
+use service_logging::{LogEntry, MezmoConfig, MezmoLogger, Severity};
+
+#[tokio::test]
+async fn mezmo_send() {
+    let server = MockServer::start();
+    let logger = MezmoLogger::init(MezmoConfig {
+        ingestion_key: "ingest-key".to_string(),
+        hostname: "web-1".to_string(),
+        app: None,
+        env: Some("prod".to_string()),
+        endpoint: format!("{}/logs/ingest", server.url),
+    })
+    .expect("init");
+
+    let mut warn = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "slow".to_string(),
+        ..Default::default()
+    };
+    warn.add_tag("db");
+    warn.custom_fields.insert("table".into(), "users".into());
+    let mut crit = LogEntry {
+        severity: Severity::Critical,
+        text: "down".to_string(),
+        ..Default::default()
+    };
+    crit.add_tag("api");
+    crit.add_tag("db");
+    logger.send("api", vec![warn, crit]).await.expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let req = &requests[0];
+    assert_eq!(req.header("authorization"), Some("user ingest-key"));
+    let (path, query) = req.path.split_once('?').expect("query string");
+    assert_eq!(path, "/logs/ingest");
+    let params: Vec<(&str, &str)> = query
+        .split('&')
+        .map(|kv| kv.split_once('=').unwrap())
+        .collect();
+    assert_eq!(params[0], ("hostname", "web-1"));
+    assert_eq!(params[1].0, "now");
+    assert!(params[1].1.parse::<u64>().is_ok());
+    assert_eq!(params[2], ("tags", "api%2Cdb"));
+
+    let body = req.json();
+    let lines = body["lines"].as_array().expect("lines");
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["timestamp"], 1_600_000_000_123u64);
+    assert_eq!(lines[0]["line"], "slow");
+    assert_eq!(lines[0]["level"], "WARN");
+    assert_eq!(lines[0]["app"], "api");
+    assert_eq!(lines[0]["env"], "prod");
+    assert_eq!(lines[0]["meta"]["table"], "users");
+    assert_eq!(lines[1]["level"], "FATAL");
+}
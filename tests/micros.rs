@@ -0,0 +1,13 @@
+// log! records microsecond timestamps when the "micros" feature is enabled
+//
+#![cfg(feature = "micros")]
+use service_logging::{make_log_entry, Severity};
+
+#[test]
+fn micros_timestamp() {
+    let e = make_log_entry!(Severity::Info, text: "hello");
+    let us = e.timestamp_us.expect("timestamp_us");
+    assert!(us >= e.timestamp * 1000);
+    assert!(us < (e.timestamp + 1) * 1000);
+    assert!(e.to_json().unwrap().contains(r#""timestampUs":"#));
+}
@@ -0,0 +1,106 @@
+// MiddlewareLogger and standard middleware
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{
+    EntryDeduplicator, FieldInjector, FieldRedactor, LogEntry, Logger, LoggerMiddleware,
+    MiddlewareLogger, Severity, SeverityFilter, VecLogger,
+};
+
+fn entry(severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn filter_and_inject() {
+    let (inner, handle) = VecLogger::new();
+    let logger = MiddlewareLogger::new(
+        inner,
+        vec![
+            Box::new(SeverityFilter::new(Severity::Warning)),
+            Box::new(FieldInjector::new([
+                ("region", "us-east-1"),
+                ("host", "web-1"),
+            ])),
+        ],
+    );
+    let mut existing = entry(Severity::Error, "existing");
+    existing.custom_fields.insert("host".into(), "db-1".into());
+    logger
+        .send(
+            "sub",
+            vec![
+                entry(Severity::Info, "info"),
+                entry(Severity::Warning, "warning"),
+                existing,
+            ],
+        )
+        .await
+        .expect("send");
+
+    let entries = handle.entries();
+    let texts: Vec<&str> = entries.iter().map(|(_, e)| e.text.as_str()).collect();
+    assert_eq!(texts, vec!["warning", "existing"]);
+    for (sub, e) in entries.iter() {
+        assert_eq!(sub, "sub");
+        assert!(e.severity >= Severity::Warning);
+        assert_eq!(e.custom_fields["region"], "us-east-1");
+    }
+    assert_eq!(entries[0].1.custom_fields["host"], "web-1");
+    // fields that are already set are not changed
+    assert_eq!(entries[1].1.custom_fields["host"], "db-1");
+}
+
+#[tokio::test]
+async fn empty_after_processing() {
+    let (inner, handle) = VecLogger::new();
+    let logger = MiddlewareLogger::new(
+        inner,
+        vec![Box::new(SeverityFilter::new(Severity::Critical))],
+    );
+    logger
+        .send("sub", vec![entry(Severity::Error, "error")])
+        .await
+        .expect("send");
+    assert!(handle.entries().is_empty());
+}
+
+#[test]
+fn redactor() {
+    let mut e = entry(Severity::Info, "secret text");
+    e.custom_fields.insert("password".into(), "hunter2".into());
+    e.custom_fields.insert("user".into(), "bob".into());
+    let out = FieldRedactor::new(["text", "password"]).process(vec![e]);
+    assert_eq!(out[0].text, "[REDACTED]");
+    assert_eq!(out[0].custom_fields["password"], "[REDACTED]");
+    assert_eq!(out[0].custom_fields["user"], "bob");
+}
+
+#[test]
+fn deduplicator() {
+    let mut with_field = entry(Severity::Info, "a");
+    with_field.custom_fields.insert("k".into(), "v".into());
+    let out = EntryDeduplicator::new().process(vec![
+        entry(Severity::Info, "a"),
+        entry(Severity::Info, "b"),
+        entry(Severity::Info, "a"),
+        entry(Severity::Error, "a"),
+        with_field,
+    ]);
+    let kept: Vec<(Severity, &str)> = out
+        .iter()
+        .map(|e| (e.severity.clone(), e.text.as_str()))
+        .collect();
+    assert_eq!(
+        kept,
+        vec![
+            (Severity::Info, "a"),
+            (Severity::Info, "b"),
+            (Severity::Error, "a"),
+            (Severity::Info, "a"),
+        ]
+    );
+}
@@ -0,0 +1,32 @@
+// global minimum severity checked by log!
+// (kept in its own test binary because the setting is process-global)
+//
+use service_logging::{get_min_severity, log, set_min_severity, LogQueue, Severity};
+use std::cell::Cell;
+
+#[test]
+fn min_severity_suppresses_entries() {
+    assert_eq!(get_min_severity(), Severity::Debug);
+    set_min_severity(Severity::Warning);
+    assert_eq!(get_min_severity(), Severity::Warning);
+
+    let mut log_queue = LogQueue::default();
+    let evaluated = Cell::new(0);
+    let value = || {
+        evaluated.set(evaluated.get() + 1);
+        "x"
+    };
+    log!(log_queue, Severity::Debug, one: value());
+    log!(log_queue, Severity::Info, one: value());
+    assert!(log_queue.is_empty());
+    assert_eq!(evaluated.get(), 0);
+
+    log!(log_queue, Severity::Warning, one: value());
+    log!(log_queue, Severity::Critical, one: value());
+    assert_eq!(evaluated.get(), 2);
+    assert_eq!(log_queue.take().len(), 2);
+
+    set_min_severity(Severity::Debug);
+    log!(log_queue, Severity::Debug, one: value());
+    assert_eq!(log_queue.take().len(), 1);
+}
@@ -0,0 +1,35 @@
+// MockLogger failure injection
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, Logger, MockLogger, Severity};
+
+fn entry(text: &str) -> LogEntry {
+    LogEntry {
+        severity: Severity::Info,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn mock_failure_schedule() {
+    let mock = MockLogger::new();
+    let logger: Box<dyn Logger + Send> = Box::new(mock.clone());
+
+    logger.send("sub", vec![entry("one")]).await.expect("send");
+    mock.fail_next(2);
+    let err = logger
+        .send("sub", vec![entry("two")])
+        .await
+        .expect_err("first failure");
+    assert!(err.to_string().contains("503"));
+    assert!(logger.send("sub", vec![entry("three")]).await.is_err());
+    logger.send("sub", vec![entry("four")]).await.expect("send");
+
+    let texts: Vec<String> = mock
+        .captured_entries()
+        .into_iter()
+        .map(|e| e.text)
+        .collect();
+    assert_eq!(texts, vec!["one", "four"]);
+}
@@ -0,0 +1,33 @@
+// MessagePack serialization
+//
+#![cfg(feature = "msgpack")]
+use service_logging::{make_log_entry, LogEntry, Severity};
+
+#[test]
+fn msgpack_round_trip() {
+    let mut entry =
+        make_log_entry!(Severity::Warning, text: "hello", category: "http", status: 200);
+    entry.source_line = Some(9);
+
+    let bytes = entry.to_msgpack().expect("encode");
+    let copy = LogEntry::from_msgpack(&bytes).expect("decode");
+    assert_eq!(copy.timestamp, entry.timestamp);
+    assert_eq!(copy.severity, Severity::Warning);
+    assert_eq!(copy.text, "hello");
+    assert_eq!(copy.category.as_deref(), Some("http"));
+    assert_eq!(copy.source_line, Some(9));
+    assert_eq!(
+        copy.custom_fields.get("status").map(|s| s.as_str()),
+        Some("200")
+    );
+    assert!(copy.class_name.is_none());
+}
+
+#[test]
+fn msgpack_smaller_than_json() {
+    let entry =
+        make_log_entry!(Severity::Info, method: "GET", url: "https://example.com", status: 200);
+    let bytes = entry.to_msgpack().expect("encode");
+    let json = entry.to_json().expect("json");
+    assert!(bytes.len() < json.len());
+}
@@ -0,0 +1,46 @@
+// NamedLogger::send_with_app
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixConfig, CoralogixLogger, LogEntry, Logger, NamedLogger};
+
+#[tokio::test]
+async fn application_name_override() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "default-app",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init");
+
+    logger
+        .send_with_app("sub", "tenant-a", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    logger
+        .send_with_app("sub", "", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    // empty batches are not sent
+    logger
+        .send_with_app("sub", "tenant-b", Vec::new())
+        .await
+        .expect("send");
+
+    let requests = server.requests();
+    let names: Vec<String> = requests
+        .iter()
+        .map(|r| r.json()["applicationName"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, vec!["tenant-a", "default-app", "default-app"]);
+    assert_eq!(requests[0].json()["subsystemName"], "sub");
+    assert_eq!(logger.stats().entries_sent, 3);
+}
@@ -0,0 +1,99 @@
+// NewRelicLogger
+//
+#![cfg(feature = "newrelic")]
+mod common;
+use common::MockServer;
+use service_logging::{LogEntry, NewRelicConfig, NewRelicLogger, Severity};
+use std::io::Read;
+
+fn logger(server: &MockServer) -> Box<dyn service_logging::Logger + Send> {
+    NewRelicLogger::init(NewRelicConfig {
+        api_key: "nr-key".to_string(),
+        endpoint: format!("{}/log/v1", server.url),
+    })
+    .expect("init")
+}
+
+fn entry(text: &str) -> LogEntry {
+    LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Error,
+        text: text.to_string(),
+        category: Some("db".to_string()),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn newrelic_send() {
+    let server = MockServer::start();
+    let mut e = entry("query failed");
+    e.custom_fields.insert("table".into(), "users".into());
+    logger(&server)
+        .send("api", vec![e, entry("second")])
+        .await
+        .expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    let req = &requests[0];
+    assert_eq!(req.path, "/log/v1");
+    assert_eq!(req.header("api-key"), Some("nr-key"));
+    assert!(req.header("content-encoding").is_none());
+
+    let payload = req.json();
+    assert_eq!(payload[0]["common"]["attributes"]["subsystem"], "api");
+    let logs = payload[0]["logs"].as_array().expect("logs array");
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0]["timestamp"], 1_600_000_000_123u64);
+    assert_eq!(logs[0]["message"], "query failed");
+    assert_eq!(logs[0]["level"], "error");
+    assert_eq!(logs[0]["category"], "db");
+    assert_eq!(logs[0]["table"], "users");
+    assert!(logs[0].get("text").is_none());
+}
+
+#[tokio::test]
+async fn newrelic_gzip_large_payload() {
+    let server = MockServer::start();
+    let text = "x".repeat(10_000);
+    let entries: Vec<LogEntry> = (0..120).map(|_| entry(&text)).collect();
+    logger(&server).send("api", entries).await.expect("send");
+
+    let requests = server.requests();
+    let req = &requests[0];
+    assert_eq!(req.header("content-encoding"), Some("gzip"));
+    let mut json = String::new();
+    flate2::read::GzDecoder::new(req.body.as_slice())
+        .read_to_string(&mut json)
+        .expect("gzip body");
+    let payload: serde_json::Value = serde_json::from_str(&json).expect("json");
+    assert_eq!(payload[0]["logs"].as_array().unwrap().len(), 120);
+}
+
+#[tokio::test]
+async fn newrelic_payload_too_large() {
+    let server = MockServer::start();
+    // pseudo-random text doesn't compress below the limit
+    let mut state = 12345u64;
+    let text: String = (0..1_500_000)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (b'!' + ((state >> 33) % 90) as u8) as char
+        })
+        .collect();
+    let err = logger(&server)
+        .send("api", vec![entry(&text)])
+        .await
+        .expect_err("too large");
+    match err {
+        service_logging::ServiceLoggingError::PayloadTooLarge { size, limit, .. } => {
+            assert_eq!(limit, 1_000_000);
+            assert!(size > limit);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(server.requests().is_empty());
+}
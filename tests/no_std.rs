@@ -0,0 +1,51 @@
+// no-std feature. Run with
+//   cargo test --no-default-features --features no-std
+// Tests that need std are not built in this configuration
+//
+#![cfg(all(feature = "no-std", not(feature = "std")))]
+#![no_std]
+extern crate alloc;
+// the test harness uses std
+extern crate std;
+
+use alloc::string::ToString;
+use service_logging::{is_enabled, log, LogEntry, LogLevel, LogQueue, Severity};
+
+#[test]
+fn log_with_timestamp() {
+    let mut lq = LogQueue::default();
+    log!(lq, Severity::Info, text: "booted", timestamp: 1_600_000_000_123u64);
+    log!(lq, Severity::Warning, sensor: "temp", value: 81);
+
+    let entries = lq.take();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].timestamp, 1_600_000_000_123);
+    assert_eq!(entries[0].text, "booted");
+    // without std there is no clock, so entries are not timestamped
+    assert_eq!(entries[1].timestamp, 0);
+    assert_eq!(entries[1].text, r#"{"sensor":"temp","value":"81"}"#);
+}
+
+#[test]
+fn queue_and_entry() {
+    let mut lq = LogQueue::default();
+    let mut e = LogEntry::with_timestamp(20);
+    e.severity = Severity::Error;
+    lq.log(e);
+    lq.log(LogEntry::with_timestamp(10));
+    lq.sort_by_timestamp();
+    assert_eq!(lq.len(), 2);
+    assert!(lq.has_errors());
+    assert_eq!(lq.take()[0].timestamp, 10);
+}
+
+#[test]
+fn severity_and_level() {
+    assert!(Severity::Error > Severity::Info);
+    assert_eq!("warning".parse::<Severity>().unwrap(), Severity::Warning);
+    assert_eq!(Severity::Critical.to_string(), "Critical");
+
+    let level = LogLevel::from_severity(Severity::Warning);
+    assert!(level.is_enabled(&Severity::Error));
+    assert!(!is_enabled(level, Severity::Info));
+}
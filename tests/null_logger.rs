@@ -0,0 +1,33 @@
+// NullLogger and silent_logger
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{silent_logger, LogEntry, Logger, NullLogger};
+use std::sync::Arc;
+
+struct Service {
+    logger: Arc<dyn Logger + Send + Sync>,
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Service {
+            logger: silent_logger(),
+        }
+    }
+}
+
+#[tokio::test]
+async fn null_logger() {
+    let logger: Arc<dyn Logger + Send + Sync> = Arc::new(NullLogger::new());
+    assert!(logger.send("sub", vec![LogEntry::default()]).await.is_ok());
+    assert!(logger.send("sub", Vec::new()).await.is_ok());
+
+    let service = Service::default();
+    let shared = service.logger.clone();
+    std::thread::spawn(move || drop(shared)).join().unwrap();
+    assert!(service
+        .logger
+        .send("sub", vec![LogEntry::default(); 3])
+        .await
+        .is_ok());
+}
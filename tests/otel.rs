@@ -0,0 +1,91 @@
+// OtelLogger
+//
+#![cfg(feature = "otel")]
+mod common;
+use common::MockServer;
+use service_logging::{LogEntry, OtelConfig, OtelLogger, Severity};
+
+fn entry() -> LogEntry {
+    let mut entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "low disk".to_string(),
+        category: Some("storage".to_string()),
+        source_line: Some(7),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("disk".into(), "sda".into());
+    entry.add_metric("bytes", 1024.0);
+    entry.with_trace("5b8efff798038103d269b633813fc60c", "eee19b7ec3c1b174")
+}
+
+#[test]
+fn otel_export_request_schema() {
+    let req = OtelLogger::export_request("api", &[entry()]);
+    let resource_logs = &req["resourceLogs"][0];
+    assert_eq!(
+        resource_logs["resource"]["attributes"][0],
+        serde_json::json!({ "key": "service.name", "value": { "stringValue": "api" } })
+    );
+    let scope_logs = &resource_logs["scopeLogs"][0];
+    assert_eq!(scope_logs["scope"]["name"], "service-logging");
+
+    let record = &scope_logs["logRecords"][0];
+    assert_eq!(record["timeUnixNano"], "1600000000123000000");
+    assert_eq!(record["severityNumber"], 13);
+    assert_eq!(record["severityText"], "WARNING");
+    assert_eq!(record["body"]["stringValue"], "low disk");
+    assert_eq!(record["traceId"], "5b8efff798038103d269b633813fc60c");
+    assert_eq!(record["spanId"], "eee19b7ec3c1b174");
+
+    let attrs = record["attributes"].as_array().unwrap();
+    let attr = |key: &str| {
+        attrs
+            .iter()
+            .find(|a| a["key"] == key)
+            .map(|a| a["value"].clone())
+            .unwrap_or_else(|| panic!("missing attribute {}", key))
+    };
+    assert_eq!(attr("category")["stringValue"], "storage");
+    assert_eq!(attr("disk")["stringValue"], "sda");
+    assert_eq!(attr("code.lineno")["intValue"], "7");
+    assert_eq!(attr("bytes")["doubleValue"], 1024.0);
+}
+
+#[test]
+fn otel_severity_numbers() {
+    let expected = [5, 6, 9, 13, 17, 21];
+    let entries: Vec<LogEntry> = Severity::iter()
+        .map(|severity| LogEntry {
+            severity,
+            ..Default::default()
+        })
+        .collect();
+    let req = OtelLogger::export_request("api", &entries);
+    let records = req["resourceLogs"][0]["scopeLogs"][0]["logRecords"]
+        .as_array()
+        .unwrap();
+    let numbers: Vec<u64> = records
+        .iter()
+        .map(|r| r["severityNumber"].as_u64().unwrap())
+        .collect();
+    assert_eq!(numbers, expected);
+    assert!(records[0].get("traceId").is_none());
+}
+
+#[tokio::test]
+async fn otel_send() {
+    let server = MockServer::start();
+    let logger = OtelLogger::init(OtelConfig {
+        exporter_endpoint: server.url.clone(),
+    })
+    .expect("init");
+    logger.send("api", vec![entry()]).await.expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/v1/logs");
+    assert_eq!(requests[0].header("content-type"), Some("application/json"));
+    let body = requests[0].json();
+    assert_eq!(body, OtelLogger::export_request("api", &[entry()]));
+}
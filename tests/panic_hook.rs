@@ -0,0 +1,73 @@
+// install_panic_hook. The hook is process-global, so this file has a single test
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{install_panic_hook, LogQueue, Severity};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn panic_hook_logs_critical() {
+    let queue = Arc::new(Mutex::new(LogQueue::default()));
+    install_panic_hook(queue.clone());
+
+    let line = line!() + 2;
+    let result = std::panic::catch_unwind(|| {
+        panic!("boom {}", 42);
+    });
+    assert!(result.is_err());
+    let result = std::panic::catch_unwind(|| {
+        std::panic::panic_any(7u32);
+    });
+    assert!(result.is_err());
+
+    let entries = queue.lock().unwrap().take();
+    assert_eq!(entries.len(), 2);
+    let e = &entries[0];
+    assert_eq!(e.severity, Severity::Critical);
+    assert!(e.text.contains("boom 42"), "{}", e.text);
+    assert!(e.text.contains(file!()), "{}", e.text);
+    assert_eq!(e.source_file.as_deref(), Some(file!()));
+    assert_eq!(e.source_line, Some(line));
+    assert!(entries[1].text.ends_with("Box<dyn Any>"));
+
+    // panic while another thread holds the queue lock: the hook waits for it
+    let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+    let holder = {
+        let queue = queue.clone();
+        std::thread::spawn(move || {
+            let _guard = queue.lock().unwrap();
+            locked_tx.send(()).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        })
+    };
+    locked_rx.recv().unwrap();
+    let result = std::panic::catch_unwind(|| {
+        panic!("while contended");
+    });
+    assert!(result.is_err());
+    holder.join().unwrap();
+    let entries = queue.lock().unwrap().take();
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].text.contains("while contended"));
+
+    // panic while this thread holds the queue lock: the hook must not deadlock
+    let locked = queue.clone();
+    let result = std::panic::catch_unwind(move || {
+        let _guard = locked.lock().unwrap();
+        panic!("while locked");
+    });
+    assert!(result.is_err());
+    // the lock is poisoned by the panic, and the entry was not queued
+    let mut guard = queue.lock().unwrap_or_else(|e| e.into_inner());
+    assert!(guard.is_empty());
+
+    // a poisoned lock is still used
+    drop(guard);
+    let result = std::panic::catch_unwind(|| {
+        panic!("after poison");
+    });
+    assert!(result.is_err());
+    guard = queue.lock().unwrap_or_else(|e| e.into_inner());
+    let entries = guard.take();
+    assert_eq!(entries.len(), 1);
+    assert!(entries[0].text.contains("after poison"));
+}
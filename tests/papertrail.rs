@@ -0,0 +1,97 @@
+// PapertrailLogger, with a local TLS listener
+//
+#![cfg(feature = "papertrail")]
+use service_logging::{LogEntry, Logger, PapertrailConfig, PapertrailLogger, Severity};
+use std::io::Read;
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+use tokio_native_tls::native_tls;
+
+const CERT: &str = include_str!("data/localhost.crt");
+const KEY: &str = include_str!("data/localhost.key");
+
+// Accepts `connections` TLS connections and sends everything read on them to the channel
+fn start_server(connections: usize) -> (u16, mpsc::Receiver<Vec<u8>>) {
+    let identity = native_tls::Identity::from_pkcs8(CERT.as_bytes(), KEY.as_bytes()).unwrap();
+    let acceptor = native_tls::TlsAcceptor::new(identity).unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for stream in listener.incoming().take(connections) {
+            let mut stream = acceptor.accept(stream.unwrap()).unwrap();
+            let tx = tx.clone();
+            thread::spawn(move || {
+                let mut buf = [0u8; 4096];
+                while let Ok(n) = stream.read(&mut buf) {
+                    if n == 0 || tx.send(buf[..n].to_vec()).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    });
+    (port, rx)
+}
+
+fn logger(port: u16) -> Box<dyn Logger + Send> {
+    PapertrailLogger::init(PapertrailConfig {
+        host: "localhost".to_string(),
+        port,
+        system_name: "web-1".to_string(),
+        ca_certificate: Some(CERT.to_string()),
+    })
+    .expect("init")
+}
+
+fn entry(text: &str) -> LogEntry {
+    LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+// reads from the channel until `count` frames have been received
+fn read_frames(rx: &mpsc::Receiver<Vec<u8>>, count: usize) -> Vec<String> {
+    let mut data = Vec::new();
+    let mut frames = Vec::new();
+    while frames.len() < count {
+        data.extend(rx.recv_timeout(std::time::Duration::from_secs(5)).unwrap());
+        frames.clear();
+        let mut rest = data.as_slice();
+        while let Some(sp) = rest.iter().position(|b| *b == b' ') {
+            let len: usize = std::str::from_utf8(&rest[..sp]).unwrap().parse().unwrap();
+            if rest.len() < sp + 1 + len {
+                break;
+            }
+            frames.push(String::from_utf8(rest[sp + 1..sp + 1 + len].to_vec()).unwrap());
+            rest = &rest[sp + 1 + len..];
+        }
+    }
+    frames
+}
+
+#[tokio::test]
+async fn papertrail_syslog_over_tls() {
+    let (port, rx) = start_server(1);
+    let logger = logger(port);
+    logger
+        .send("api", vec![entry("first"), entry("second")])
+        .await
+        .expect("send");
+    // second send reuses the connection; the server only accepts one
+    logger
+        .send("api", vec![entry("third")])
+        .await
+        .expect("send");
+
+    let frames = read_frames(&rx, 3);
+    let re =
+        regex::Regex::new(r"^<12>1 2020-09-13T12:26:40\.123Z web-1 api \d+ - - first$").unwrap();
+    assert!(re.is_match(&frames[0]), "{}", frames[0]);
+    assert!(frames[1].ends_with(" second"));
+    assert!(frames[2].ends_with(" third"));
+}
@@ -0,0 +1,41 @@
+// LogQueue pop and peek
+//
+use service_logging::{LogEntry, LogQueue};
+
+fn queue() -> LogQueue {
+    LogQueue::from(
+        ["a", "b", "c"]
+            .iter()
+            .map(|text| LogEntry {
+                text: text.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn fifo() {
+    let mut lq = queue();
+    assert_eq!(lq.peek_front().map(|e| e.text.as_str()), Some("a"));
+    assert_eq!(lq.len(), 3);
+    let texts: Vec<String> = std::iter::from_fn(|| lq.pop_front())
+        .map(|e| e.text)
+        .collect();
+    assert_eq!(texts, vec!["a", "b", "c"]);
+    assert!(lq.peek_front().is_none());
+    assert!(lq.pop_front().is_none());
+}
+
+#[test]
+fn lifo() {
+    let mut lq = queue();
+    assert_eq!(lq.peek_back().map(|e| e.text.as_str()), Some("c"));
+    assert_eq!(lq.pop_back().map(|e| e.text), Some("c".to_string()));
+    assert_eq!(lq.pop_back().map(|e| e.text), Some("b".to_string()));
+    assert_eq!(lq.peek_front().map(|e| e.text.as_str()), Some("a"));
+    assert_eq!(lq.len(), 1);
+    lq.pop_back();
+    assert!(lq.peek_back().is_none());
+    assert!(lq.pop_back().is_none());
+}
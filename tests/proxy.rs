@@ -0,0 +1,53 @@
+// CoralogixLogger proxy_url
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+#![cfg(not(target_arch = "wasm32"))]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixConfig, CoralogixLogger, LogEntry, Logger, ServiceLoggingError};
+
+#[tokio::test]
+async fn send_through_proxy() {
+    // the mock server is the proxy. Nothing listens at the endpoint
+    let proxy = MockServer::start();
+    let proxy_url = proxy.url.replace("http://", "http://user:pass@");
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: "http://localhost:9/api/v1/logs",
+        proxy_url: Some(&proxy_url),
+        ..Default::default()
+    })
+    .expect("init");
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+
+    let requests = proxy.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "http://localhost:9/api/v1/logs");
+    // base64 of "user:pass"
+    assert_eq!(
+        requests[0].header("proxy-authorization"),
+        Some("Basic dXNlcjpwYXNz")
+    );
+    assert_eq!(requests[0].json()["applicationName"], "app");
+}
+
+#[test]
+fn invalid_proxy_url() {
+    let err = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: "https://api.coralogix.com/api/v1/logs",
+        proxy_url: Some("http://bad host:8080"),
+        ..Default::default()
+    })
+    .unwrap_err();
+    assert!(
+        matches!(&err, ServiceLoggingError::ConfigError(msg) if msg.starts_with("Invalid proxy url")),
+        "{:?}",
+        err
+    );
+}
@@ -0,0 +1,42 @@
+// LogQueue::push_front
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LogQueue, Severity, VecLogger};
+
+fn entry(severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn push_front_first() {
+    let mut lq = LogQueue::default();
+    lq.log(entry(Severity::Debug, "trace 1"));
+    lq.log(entry(Severity::Debug, "trace 2"));
+    lq.push_front(entry(Severity::Critical, "failed"));
+
+    let lines = lq.to_json_lines().unwrap();
+    let first: LogEntry = serde_json::from_str(lines.lines().next().unwrap()).unwrap();
+    assert_eq!(first.text, "failed");
+
+    let (logger, handle) = VecLogger::new();
+    logger.send("sub", lq.take()).await.unwrap();
+    let texts: Vec<String> = handle.entries().into_iter().map(|(_, e)| e.text).collect();
+    assert_eq!(texts, vec!["failed", "trace 1", "trace 2"]);
+}
+
+#[test]
+fn push_front_empty() {
+    let mut a = LogQueue::default();
+    a.push_front(entry(Severity::Info, "x"));
+    let mut b = LogQueue::default();
+    b.log(entry(Severity::Info, "x"));
+    assert_eq!(a.len(), 1);
+    assert_eq!(
+        a.take().into_iter().map(|e| e.text).collect::<Vec<_>>(),
+        b.take().into_iter().map(|e| e.text).collect::<Vec<_>>()
+    );
+}
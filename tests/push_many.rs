@@ -0,0 +1,83 @@
+// LogQueue::push_many and LogQueue::with_capacity
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+use std::time::Instant;
+
+fn entries(n: usize) -> Vec<LogEntry> {
+    (0..n)
+        .map(|i| LogEntry {
+            severity: Severity::Info,
+            text: format!("entry {}", i),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[test]
+fn push_many_appends_in_order() {
+    let mut lq = LogQueue::new();
+    lq.log(LogEntry {
+        text: "first".to_string(),
+        ..Default::default()
+    });
+    lq.push_many(entries(3));
+    let texts: Vec<String> = lq.take().into_iter().map(|e| e.text).collect();
+    assert_eq!(texts, vec!["first", "entry 0", "entry 1", "entry 2"]);
+}
+
+#[test]
+fn push_many_empty() {
+    let mut lq = LogQueue::new();
+    lq.push_many(Vec::new());
+    assert!(lq.is_empty());
+}
+
+#[test]
+fn extend() {
+    let mut lq = LogQueue::new();
+    lq.extend(entries(2));
+    lq.extend(entries(3).into_iter().filter(|e| e.text != "entry 1"));
+    assert_eq!(lq.len(), 4);
+}
+
+#[test]
+fn with_capacity() {
+    let mut lq = LogQueue::with_capacity(100);
+    assert!(lq.is_empty());
+    assert!(lq.capacity() >= 100);
+    lq.push_many(entries(100));
+    assert_eq!(lq.len(), 100);
+}
+
+// timing-dependent: run with `cargo test --release -- --ignored`
+#[test]
+#[ignore]
+fn push_many_faster_than_log() {
+    const N: usize = 10_000;
+    const ROUNDS: usize = 20;
+    let mut individual = std::time::Duration::default();
+    let mut bulk = std::time::Duration::default();
+    for _ in 0..ROUNDS {
+        let batch = entries(N);
+        let mut lq = LogQueue::new();
+        let start = Instant::now();
+        for e in batch {
+            lq.log(e);
+        }
+        individual += start.elapsed();
+        assert_eq!(lq.len(), N);
+
+        let batch = entries(N);
+        let mut lq = LogQueue::new();
+        let start = Instant::now();
+        lq.push_many(batch);
+        bulk += start.elapsed();
+        assert_eq!(lq.len(), N);
+    }
+    assert!(
+        bulk < individual,
+        "push_many {:?}, log {:?}",
+        bulk,
+        individual
+    );
+}
@@ -0,0 +1,76 @@
+// LogQueue summary and display
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn entry(timestamp: u64, severity: Severity) -> LogEntry {
+    LogEntry {
+        timestamp,
+        severity,
+        text: "t".to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn summary_counts() {
+    let mut entries = Vec::new();
+    for i in 0..5 {
+        entries.push(entry(i, Severity::Info));
+    }
+    entries.push(entry(5, Severity::Warning));
+    entries.push(entry(6, Severity::Warning));
+    entries.push(entry(7, Severity::Critical));
+    let log_queue = LogQueue::from(entries);
+
+    let expected =
+        "LogQueue(8 entries: 0 debug, 0 verbose, 5 info, 2 warning, 0 error, 1 critical)";
+    assert_eq!(log_queue.summary(), expected);
+    assert_eq!(log_queue.to_string(), expected);
+    assert_eq!(log_queue.len(), 8);
+    assert_eq!(
+        LogQueue::default().to_string(),
+        "LogQueue(0 entries: 0 debug, 0 verbose, 0 info, 0 warning, 0 error, 0 critical)"
+    );
+}
+
+#[test]
+fn display_entries() {
+    let log_queue = LogQueue::from(vec![entry(1, Severity::Info), entry(2, Severity::Error)]);
+    assert_eq!(log_queue.display_entries(), "1 Info t\n2 Error t");
+}
+
+#[test]
+fn entry_display_full() {
+    let mut e = LogEntry {
+        timestamp: 1_704_067_200_000,
+        severity: Severity::Info,
+        text: "message text".to_string(),
+        category: Some("auth".to_string()),
+        method_name: Some("login".to_string()),
+        thread_id: Some("7".to_string()),
+        ..Default::default()
+    };
+    e.custom_fields.insert("user".into(), "bob".into());
+    assert_eq!(
+        e.display_full(),
+        "[2024-01-01T00:00:00.000Z] [Info] [auth/login] message text {thread_id=7, user=bob}"
+    );
+    // concise
+    assert_eq!(e.to_string(), "1704067200000 Info message text");
+
+    let bare = entry(1_704_067_200_000, Severity::Error);
+    assert_eq!(bare.display_full(), "[2024-01-01T00:00:00.000Z] [Error] t");
+}
+
+#[test]
+fn queue_debug() {
+    let log_queue = LogQueue::from(vec![entry(0, Severity::Info), entry(0, Severity::Error)]);
+    assert_eq!(
+        format!("{:?}", log_queue),
+        "LogQueue [[1970-01-01T00:00:00.000Z] [Info] t, [1970-01-01T00:00:00.000Z] [Error] t]"
+    );
+    assert_eq!(
+        format!("{:#?}", log_queue),
+        "LogQueue [\n    [1970-01-01T00:00:00.000Z] [Info] t,\n    [1970-01-01T00:00:00.000Z] [Error] t,\n]"
+    );
+}
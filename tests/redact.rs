@@ -0,0 +1,122 @@
+// LogEntry::redact_field and redact_all_fields
+//
+use service_logging::{LogEntry, Severity};
+
+const REDACTED: &str = "[REDACTED]";
+
+fn entry() -> LogEntry {
+    let mut e = LogEntry {
+        timestamp: 1234,
+        severity: Severity::Warning,
+        text: "password=hunter2".to_string(),
+        category: Some("auth".to_string()),
+        class_name: Some("Login".to_string()),
+        method_name: Some("check".to_string()),
+        thread_id: Some("7".to_string()),
+        ..Default::default()
+    };
+    e.custom_fields.insert("user".into(), "bob".into());
+    e.custom_fields.insert("ip".into(), "10.0.0.1".into());
+    e
+}
+
+#[test]
+fn redact_named_fields() {
+    let mut e = entry();
+    e.redact_field("text").redact_field("category");
+    assert_eq!(e.text, REDACTED);
+    assert_eq!(e.category.as_deref(), Some(REDACTED));
+    assert_eq!(e.class_name.as_deref(), Some("Login"));
+
+    e.redact_field("class_name")
+        .redact_field("method_name")
+        .redact_field("thread_id")
+        .redact_field("user");
+    assert_eq!(e.class_name.as_deref(), Some(REDACTED));
+    assert_eq!(e.method_name.as_deref(), Some(REDACTED));
+    assert_eq!(e.thread_id.as_deref(), Some(REDACTED));
+    assert_eq!(e.custom_fields.get("user").unwrap(), REDACTED);
+    assert_eq!(e.custom_fields.get("ip").unwrap(), "10.0.0.1");
+
+    e.trace_id = Some("abc".to_string());
+    e.add_tag("t1");
+    e.redact_field("trace_id").redact_field("tags");
+    assert_eq!(e.trace_id.as_deref(), Some(REDACTED));
+    assert_eq!(e.tags, vec![REDACTED]);
+
+    // unknown names don't add custom fields
+    e.redact_field("missing");
+    assert!(!e.custom_fields.contains_key("missing"));
+}
+
+#[test]
+fn redact_none_unchanged() {
+    let mut e = LogEntry::default();
+    e.redact_field("category").redact_all_fields();
+    assert_eq!(e.category, None);
+    assert_eq!(e.class_name, None);
+    assert_eq!(e.method_name, None);
+    assert_eq!(e.thread_id, None);
+    assert_eq!(e.trace_id, None);
+    assert_eq!(e.hostname, None);
+    assert!(e.tags.is_empty());
+    assert_eq!(e.text, "");
+}
+
+#[test]
+fn redact_all() {
+    let mut e = entry();
+    e.source_file = Some("src/login.rs".to_string());
+    e.source_line = Some(12);
+    e.source_module = Some("app::login".to_string());
+    e.trace_id = Some("4bf92f3577b34da6a3ce929d0e0e4736".to_string());
+    e.span_id = Some("00f067aa0ba902b7".to_string());
+    e.environment = Some("prod".to_string());
+    e.app_version = Some("1.2.3".to_string());
+    e.hostname = Some("web-1".to_string());
+    e.add_tag("customer-42").add_tag("beta");
+    e.redact_all_fields();
+    for val in [
+        &e.category,
+        &e.class_name,
+        &e.method_name,
+        &e.thread_id,
+        &e.source_file,
+        &e.source_module,
+        &e.trace_id,
+        &e.span_id,
+        &e.environment,
+        &e.app_version,
+        &e.hostname,
+    ] {
+        assert_eq!(val.as_deref(), Some(REDACTED));
+    }
+    assert_eq!(e.tags, vec![REDACTED, REDACTED]);
+    assert_eq!(e.source_line, Some(12));
+    let json = serde_json::to_string(&e).unwrap();
+    for secret in [
+        "hunter2",
+        "auth",
+        "Login",
+        "login",
+        "bob",
+        "10.0.0.1",
+        "4bf92f",
+        "00f067",
+        "prod",
+        "1.2.3",
+        "web-1",
+        "customer-42",
+        "beta",
+    ] {
+        assert!(!json.contains(secret), "{} in {}", secret, json);
+    }
+    assert_eq!(e.text, REDACTED);
+    assert_eq!(e.category.as_deref(), Some(REDACTED));
+    assert_eq!(e.class_name.as_deref(), Some(REDACTED));
+    assert_eq!(e.method_name.as_deref(), Some(REDACTED));
+    assert_eq!(e.thread_id.as_deref(), Some(REDACTED));
+    assert!(e.custom_fields.values().all(|v| v == REDACTED));
+    assert_eq!(e.timestamp, 1234);
+    assert_eq!(e.severity, Severity::Warning);
+}
@@ -0,0 +1,81 @@
+// CoralogixRegion and CoralogixConfig::region
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixConfig, CoralogixLogger, CoralogixRegion, LogEntry, Logger};
+
+#[test]
+fn region_endpoints() {
+    let expected = [
+        (
+            CoralogixRegion::UsEast,
+            "https://api.coralogix.us/api/v1/logs",
+        ),
+        (
+            CoralogixRegion::UsWest,
+            "https://api.cx498.coralogix.com/api/v1/logs",
+        ),
+        (
+            CoralogixRegion::Europe,
+            "https://api.coralogix.com/api/v1/logs",
+        ),
+        (
+            CoralogixRegion::Europe2,
+            "https://api.eu2.coralogix.com/api/v1/logs",
+        ),
+        (
+            CoralogixRegion::India,
+            "https://api.app.coralogix.in/api/v1/logs",
+        ),
+        (
+            CoralogixRegion::Singapore,
+            "https://api.coralogixsg.com/api/v1/logs",
+        ),
+        (
+            CoralogixRegion::Indonesia,
+            "https://api.ap3.coralogix.com/api/v1/logs",
+        ),
+    ];
+    for (region, endpoint) in expected.iter() {
+        assert_eq!(region.endpoint(), *endpoint);
+        let config = CoralogixConfig {
+            api_key: "key",
+            application_name: "app",
+            region: Some(*region),
+            ..Default::default()
+        };
+        assert_eq!(config.endpoint_url(), *endpoint);
+        assert!(config.validate().is_ok());
+    }
+}
+
+#[test]
+fn endpoint_required() {
+    let config = CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        ..Default::default()
+    };
+    assert_eq!(config.endpoint_url(), "");
+    assert!(config.validate().is_err());
+}
+
+#[tokio::test]
+async fn endpoint_overrides_region() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        region: Some(CoralogixRegion::Europe),
+        ..Default::default()
+    })
+    .expect("init");
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    assert_eq!(server.requests().len(), 1);
+}
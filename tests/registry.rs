@@ -0,0 +1,57 @@
+// LoggerRegistry
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LoggerRegistry, ServiceLoggingError, VecLogger, LOGGER_REGISTRY};
+
+fn entry(text: &str) -> LogEntry {
+    LogEntry {
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn registry_send_by_name() {
+    let (tenant_a, handle_a) = VecLogger::new();
+    let (tenant_b, handle_b) = VecLogger::new();
+    let mut registry = LoggerRegistry::new();
+    registry.register("a", tenant_a);
+    registry.register(String::from("b"), tenant_b);
+    assert_eq!(registry.names().collect::<Vec<_>>(), vec!["a", "b"]);
+
+    registry
+        .send("a", "sub", vec![entry("to a")])
+        .await
+        .unwrap();
+    registry
+        .send("b", "sub", vec![entry("to b")])
+        .await
+        .unwrap();
+    registry
+        .get("b")
+        .expect("registered")
+        .send("sub", vec![entry("to b again")])
+        .await
+        .unwrap();
+
+    assert_eq!(handle_a.entries().len(), 1);
+    assert!(handle_a.has_entry_matching(|e| e.text == "to a"));
+    assert_eq!(handle_b.entries().len(), 2);
+    assert!(!handle_b.has_entry_matching(|e| e.text == "to a"));
+
+    assert!(registry.get("c").is_none());
+    match registry.send("c", "sub", vec![entry("lost")]).await {
+        Err(ServiceLoggingError::ConfigError(msg)) => assert!(msg.contains("'c'")),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    assert!(registry.unregister("a").is_some());
+    assert!(registry.get("a").is_none());
+}
+
+#[test]
+fn global_registry() {
+    let (logger, _handle) = VecLogger::new();
+    LOGGER_REGISTRY.lock().unwrap().register("global", logger);
+    assert!(LOGGER_REGISTRY.lock().unwrap().get("global").is_some());
+}
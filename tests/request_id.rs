@@ -0,0 +1,177 @@
+// CoralogixLogger request ids
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{
+    CoralogixConfig, CoralogixLogger, LogEntry, LogEntrySerializer, Logger, ServiceLoggingError,
+};
+use std::sync::Arc;
+
+fn logger(url: &str, debug_requests: bool) -> CoralogixLogger {
+    let endpoint = format!("{}/api/v1/logs", url);
+    CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        debug_requests,
+        ..Default::default()
+    })
+    .expect("init")
+}
+
+// parses the request id from an error message
+fn request_id(err: &ServiceLoggingError) -> u64 {
+    let msg = err.to_string();
+    let start = msg.find("[request_id=").expect("request id") + "[request_id=".len();
+    let end = start + msg[start..].find(']').unwrap();
+    msg[start..end].parse().unwrap()
+}
+
+#[tokio::test]
+async fn error_contains_request_id() {
+    let server = MockServer::with_statuses(vec![503]);
+    let logger = logger(&server.url, false);
+    let err = logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Coralogix send failed [request_id=1]: status 503 status 503"
+    );
+    match err {
+        ServiceLoggingError::BackendError {
+            status,
+            body,
+            request_id,
+        } => {
+            assert_eq!(status, 503);
+            // the response body is unchanged
+            assert_eq!(body, "status 503");
+            assert_eq!(request_id, Some(1));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn network_error_contains_request_id() {
+    // bind and drop a listener to get a port with nothing listening
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let logger = logger(&format!("http://127.0.0.1:{}", port), false);
+    let err = logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ServiceLoggingError::NetworkError(_)));
+    assert!(
+        err.to_string()
+            .contains("Coralogix send failed [request_id=1]: "),
+        "{}",
+        err
+    );
+}
+
+#[derive(Debug)]
+struct FailingSerializer;
+
+impl LogEntrySerializer for FailingSerializer {
+    fn serialize(
+        &self,
+        _entry: &LogEntry,
+        _backend: &str,
+    ) -> Result<serde_json::Value, ServiceLoggingError> {
+        Err(serde_json::from_str::<serde_json::Value>("{")
+            .unwrap_err()
+            .into())
+    }
+}
+
+#[tokio::test]
+async fn serialization_error_contains_request_id() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        serializer: Some(Arc::new(FailingSerializer)),
+        ..Default::default()
+    })
+    .expect("init");
+    let err = logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("Coralogix send failed [request_id=1]: Serialization Error: "),
+        "{}",
+        err
+    );
+    match &err {
+        ServiceLoggingError::RequestFailed { request_id, source } => {
+            assert_eq!(*request_id, 1);
+            assert!(matches!(
+                **source,
+                ServiceLoggingError::SerializationError(_)
+            ));
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(std::error::Error::source(&err).is_some());
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn request_ids_increase() {
+    let server = MockServer::with_statuses(vec![200, 500]);
+    let logger = logger(&server.url, true);
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    // empty batches are not sent, and don't use an id
+    logger.send("sub", Vec::new()).await.expect("empty");
+    let second = logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .unwrap_err();
+    let third = logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .unwrap_err();
+    assert_eq!(request_id(&second), 2);
+    assert_eq!(request_id(&third), 3);
+}
+
+#[tokio::test]
+async fn concurrent_request_ids_unique() {
+    let server = MockServer::with_statuses(vec![503]);
+    let logger = logger(&server.url, false);
+    // clones share the counter
+    let other = logger.clone();
+    let (a, b, c, d) = tokio::join!(
+        logger.send("a", vec![LogEntry::default()]),
+        other.send("b", vec![LogEntry::default()]),
+        logger.send("c", vec![LogEntry::default()]),
+        other.send("d", vec![LogEntry::default()]),
+    );
+    let mut ids: Vec<u64> = [a, b, c, d]
+        .iter()
+        .map(|r| request_id(r.as_ref().unwrap_err()))
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec![1, 2, 3, 4]);
+
+    let next = logger
+        .send("e", vec![LogEntry::default()])
+        .await
+        .unwrap_err();
+    assert_eq!(request_id(&next), 5);
+}
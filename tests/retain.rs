@@ -0,0 +1,46 @@
+// LogQueue::retain and into_filtered
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn queue() -> LogQueue {
+    LogQueue::from(
+        [
+            Severity::Debug,
+            Severity::Warning,
+            Severity::Info,
+            Severity::Critical,
+            Severity::Error,
+            Severity::Verbose,
+        ]
+        .iter()
+        .enumerate()
+        .map(|(n, severity)| LogEntry {
+            severity: severity.clone(),
+            text: n.to_string(),
+            ..Default::default()
+        })
+        .collect(),
+    )
+}
+
+fn texts(mut lq: LogQueue) -> Vec<String> {
+    lq.take().into_iter().map(|e| e.text).collect()
+}
+
+#[test]
+fn retain() {
+    let mut lq = queue();
+    lq.retain(|e| e.severity >= Severity::Warning);
+    assert_eq!(lq.len(), 3);
+    assert_eq!(texts(lq), vec!["1", "3", "4"]);
+
+    let mut lq = queue();
+    lq.retain(|_| false);
+    assert!(lq.is_empty());
+}
+
+#[test]
+fn into_filtered() {
+    let filtered = queue().into_filtered(|e| e.severity < Severity::Info);
+    assert_eq!(texts(filtered), vec!["0", "5"]);
+}
@@ -0,0 +1,111 @@
+// SentryLogger
+//
+#![cfg(feature = "sentry")]
+mod common;
+use common::MockServer;
+use service_logging::{LogEntry, SentryConfig, SentryLogger, Severity};
+
+fn entry(severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        timestamp: 1_600_000_000_000,
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn sentry_envelope() {
+    let server = MockServer::start();
+    let dsn = format!("{}/42", server.url.replace("http://", "http://public@"));
+    let logger = SentryLogger::init(SentryConfig {
+        dsn: dsn.clone(),
+        environment: Some("staging".to_string()),
+        release: Some("1.0".to_string()),
+    })
+    .expect("init");
+
+    let mut err = entry(Severity::Error, "disk failed");
+    err.category = Some("storage".to_string());
+    err.class_name = Some("Disk".to_string());
+    err.source_file = Some("src/disk.rs".to_string());
+    err.source_line = Some(12);
+    logger
+        .send(
+            "sub",
+            vec![
+                entry(Severity::Info, "not sent"),
+                err,
+                entry(Severity::Critical, "fatal"),
+            ],
+        )
+        .await
+        .expect("send");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    let req = &requests[0];
+    assert_eq!(req.path, "/api/42/envelope/");
+    assert!(req
+        .header("x-sentry-auth")
+        .unwrap()
+        .contains("sentry_key=public"));
+    assert_eq!(
+        req.header("content-type"),
+        Some("application/x-sentry-envelope")
+    );
+
+    let body = req.body_string();
+    let lines: Vec<&str> = body.lines().collect();
+    assert_eq!(lines.len(), 3);
+    let header: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    let item: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+    let event: serde_json::Value = serde_json::from_str(lines[2]).unwrap();
+    assert_eq!(header["dsn"], dsn.as_str());
+    assert_eq!(header["event_id"], event["event_id"]);
+    assert_eq!(event["event_id"].as_str().unwrap().len(), 32);
+    assert_eq!(item["type"], "event");
+    assert_eq!(item["length"], lines[2].len());
+    assert_eq!(event["level"], "error");
+    assert_eq!(event["logentry"]["formatted"], "disk failed");
+    assert_eq!(event["tags"]["category"], "storage");
+    assert_eq!(event["culprit"], "src/disk.rs:12");
+    assert_eq!(
+        event["fingerprint"],
+        serde_json::json!(["disk failed", "Disk"])
+    );
+    assert_eq!(event["environment"], "staging");
+    assert_eq!(event["release"], "1.0");
+
+    let fatal: serde_json::Value =
+        serde_json::from_str(requests[1].body_string().lines().nth(2).unwrap()).unwrap();
+    assert_eq!(fatal["level"], "fatal");
+}
+
+#[tokio::test]
+async fn sentry_drops_low_severity() {
+    let server = MockServer::start();
+    let dsn = format!("{}/42", server.url.replace("http://", "http://public@"));
+    let logger = SentryLogger::init(SentryConfig {
+        dsn,
+        ..Default::default()
+    })
+    .expect("init");
+    logger
+        .send(
+            "sub",
+            vec![entry(Severity::Info, "a"), entry(Severity::Warning, "b")],
+        )
+        .await
+        .expect("send");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn sentry_invalid_dsn() {
+    assert!(SentryLogger::init(SentryConfig {
+        dsn: "not a dsn".to_string(),
+        ..Default::default()
+    })
+    .is_err());
+}
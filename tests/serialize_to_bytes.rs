@@ -0,0 +1,128 @@
+// LogQueue::serialize_to_bytes and LogQueue::from_bytes
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LogQueue, SerializationFormat, Severity};
+
+fn queue() -> LogQueue {
+    let mut first = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "disk low".to_string(),
+        category: Some("storage".to_string()),
+        source_line: Some(42),
+        ..Default::default()
+    };
+    first.custom_fields.insert("free_mb".into(), "10".into());
+    first.add_metric("latency_ms", 12.5);
+    let second = LogEntry {
+        timestamp: 1_600_000_000_456,
+        severity: Severity::Info,
+        text: "line one\nline \"two\"".to_string(),
+        ..Default::default()
+    };
+    LogQueue::from(vec![first, second])
+}
+
+// compares all fields, since LogEntry equality only compares timestamp and severity
+fn assert_same(a: &LogQueue, b: &LogQueue) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.to_json_lines().unwrap(), b.to_json_lines().unwrap());
+}
+
+#[test]
+fn json_lines_round_trip() {
+    let lq = queue();
+    let bytes = lq
+        .serialize_to_bytes(SerializationFormat::JsonLines)
+        .unwrap();
+    assert_eq!(bytes, lq.to_json_lines().unwrap().into_bytes());
+    let parsed = LogQueue::from_bytes(&bytes, SerializationFormat::JsonLines).unwrap();
+    assert_same(&lq, &parsed);
+}
+
+#[test]
+fn json_round_trip() {
+    let lq = queue();
+    let bytes = lq.serialize_to_bytes(SerializationFormat::Json).unwrap();
+    let val: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(val.as_array().unwrap().len(), 2);
+    let parsed = LogQueue::from_bytes(&bytes, SerializationFormat::Json).unwrap();
+    assert_same(&lq, &parsed);
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_round_trip() {
+    let lq = queue();
+    let bytes = lq.serialize_to_bytes(SerializationFormat::Msgpack).unwrap();
+    let parsed = LogQueue::from_bytes(&bytes, SerializationFormat::Msgpack).unwrap();
+    assert_same(&lq, &parsed);
+    assert!(LogQueue::from_bytes(&bytes[..bytes.len() / 2], SerializationFormat::Msgpack).is_err());
+}
+
+#[test]
+fn csv() {
+    let lq = queue();
+    let fields = vec!["severity".to_string(), "text".to_string()];
+    let bytes = lq
+        .serialize_to_bytes(SerializationFormat::Csv {
+            fields: fields.clone(),
+        })
+        .unwrap();
+    assert_eq!(bytes, lq.to_csv(&["severity", "text"]).into_bytes());
+    assert!(LogQueue::from_bytes(&bytes, SerializationFormat::Csv { fields }).is_err());
+}
+
+#[test]
+fn gelf_valid() {
+    let lq = queue();
+    let bytes = lq
+        .serialize_to_bytes(SerializationFormat::Gelf {
+            host: "web-1".to_string(),
+        })
+        .unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 2);
+    for line in lines {
+        let obj: serde_json::Value = serde_json::from_str(line).unwrap();
+        let obj = obj.as_object().unwrap();
+        // required fields
+        assert_eq!(obj["version"], "1.1");
+        assert_eq!(obj["host"], "web-1");
+        assert!(obj["short_message"].as_str().is_some());
+        // optional fields
+        assert!(obj["timestamp"].as_f64().is_some());
+        assert!(obj["level"].as_u64().unwrap() <= 7);
+        // additional fields are prefixed with '_', and "_id" is reserved
+        for key in obj.keys() {
+            assert!(
+                ["version", "host", "short_message", "timestamp", "level"].contains(&key.as_str())
+                    || (key.starts_with('_') && key != "_id"),
+                "{}",
+                key
+            );
+        }
+    }
+    let first: serde_json::Value = serde_json::from_str(text.lines().next().unwrap()).unwrap();
+    assert_eq!(first["timestamp"], 1_600_000_000.123);
+    assert_eq!(first["_free_mb"], "10");
+}
+
+#[test]
+fn gelf_empty_host() {
+    let bytes = queue()
+        .serialize_to_bytes(SerializationFormat::Gelf {
+            host: String::new(),
+        })
+        .unwrap();
+    let first: serde_json::Value =
+        serde_json::from_slice(bytes.split(|b| *b == b'\n').next().unwrap()).unwrap();
+    assert_eq!(first["host"], "-");
+}
+
+#[test]
+fn from_bytes_invalid() {
+    assert!(LogQueue::from_bytes(b"{not json", SerializationFormat::JsonLines).is_err());
+    assert!(LogQueue::from_bytes(b"{}", SerializationFormat::Json).is_err());
+}
@@ -0,0 +1,91 @@
+// LogEntrySerializer implementations
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{
+    CoralogixConfig, CoralogixLogger, CoralogixSerializer, EcsSerializer, GelfSerializer, LogEntry,
+    LogEntrySerializer, Logger, Severity,
+};
+use std::sync::Arc;
+
+fn entry() -> LogEntry {
+    let mut entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Warning,
+        text: "disk full".to_string(),
+        class_name: Some("Storage".to_string()),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("volume".into(), "/data".into());
+    entry
+}
+
+#[test]
+fn coralogix() {
+    let value = CoralogixSerializer.serialize(&entry(), "test").unwrap();
+    assert_eq!(value, serde_json::to_value(entry()).unwrap());
+    assert_eq!(value["timestamp"], 1_600_000_000_123u64);
+    assert_eq!(value["severity"], 4);
+    assert_eq!(value["className"], "Storage");
+    assert_eq!(value["customFields"]["volume"], "/data");
+}
+
+#[test]
+fn ecs() {
+    let value = EcsSerializer.serialize(&entry(), "test").unwrap();
+    assert_eq!(value, entry().to_ecs());
+    assert_eq!(value["@timestamp"], "2020-09-13T12:26:40.123Z");
+    assert_eq!(value["log"]["level"], "warning");
+    assert_eq!(value["log"]["logger"], "Storage");
+    assert_eq!(value["labels"]["volume"], "/data");
+}
+
+#[test]
+fn gelf() {
+    let value = GelfSerializer::new("web-1")
+        .serialize(&entry(), "test")
+        .unwrap();
+    assert_eq!(value, entry().to_gelf("web-1"));
+    assert_eq!(value["host"], "web-1");
+    assert_eq!(value["short_message"], "disk full");
+    assert_eq!(value["timestamp"], 1_600_000_000.123);
+    assert_eq!(value["level"], 4);
+    assert_eq!(value["_volume"], "/data");
+
+    let value = GelfSerializer::default()
+        .serialize(&entry(), "test")
+        .unwrap();
+    assert_eq!(value["host"], "-");
+}
+
+#[test]
+fn trait_object() {
+    let serializers: Vec<Box<dyn LogEntrySerializer>> = vec![
+        Box::new(CoralogixSerializer),
+        Box::new(EcsSerializer),
+        Box::new(GelfSerializer::default()),
+    ];
+    for serializer in serializers.iter() {
+        assert!(serializer.serialize(&entry(), "test").unwrap().is_object());
+    }
+}
+
+#[tokio::test]
+async fn coralogix_logger_serializer() {
+    let server = MockServer::start();
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        serializer: Some(Arc::new(EcsSerializer)),
+        ..Default::default()
+    })
+    .expect("init");
+    logger.send("sub", vec![entry()]).await.expect("send");
+
+    let body = server.requests()[0].json();
+    assert_eq!(body["logEntries"][0], entry().to_ecs());
+    assert_eq!(body["subsystemName"], "sub");
+}
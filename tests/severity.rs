@@ -0,0 +1,45 @@
+// Severity helpers
+//
+use service_logging::Severity;
+
+#[test]
+fn severity_iter() {
+    let all: Vec<Severity> = Severity::iter().collect();
+    assert_eq!(
+        all,
+        vec![
+            Severity::Debug,
+            Severity::Verbose,
+            Severity::Info,
+            Severity::Warning,
+            Severity::Error,
+            Severity::Critical
+        ]
+    );
+    assert_eq!(Severity::count(), 6);
+    assert_eq!(Severity::iter().count(), Severity::count());
+    for sev in Severity::iter() {
+        assert_eq!(sev.to_string().parse::<Severity>(), Ok(sev));
+    }
+}
+
+#[test]
+fn severity_next_prev() {
+    assert_eq!(Severity::Debug.prev(), None);
+    assert_eq!(Severity::Critical.next(), None);
+
+    let all: Vec<Severity> = Severity::iter().collect();
+    for pair in all.windows(2) {
+        assert_eq!(pair[0].next().as_ref(), Some(&pair[1]));
+        assert_eq!(pair[1].prev().as_ref(), Some(&pair[0]));
+    }
+
+    // step from lowest to highest
+    let mut sev = Some(Severity::Debug);
+    let mut steps = 0;
+    while let Some(s) = sev {
+        sev = s.next();
+        steps += 1;
+    }
+    assert_eq!(steps, Severity::count());
+}
@@ -0,0 +1,38 @@
+// Severity deserialization from integers and strings
+//
+use service_logging::Severity;
+
+fn parse(json: &str) -> Result<Severity, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn severity_from_int_or_string() {
+    assert_eq!(parse("4").unwrap(), Severity::Warning);
+    assert_eq!(parse("\"4\"").unwrap(), Severity::Warning);
+    assert_eq!(parse("\"Warning\"").unwrap(), Severity::Warning);
+    assert_eq!(parse("\"warning\"").unwrap(), Severity::Warning);
+    assert_eq!(parse("\"CRITICAL\"").unwrap(), Severity::Critical);
+    assert_eq!(parse("\"dEbUg\"").unwrap(), Severity::Debug);
+}
+
+#[test]
+fn severity_serializes_as_int() {
+    assert_eq!(serde_json::to_string(&Severity::Error).unwrap(), "5");
+    assert_eq!(
+        parse(&serde_json::to_string(&Severity::Error).unwrap()).unwrap(),
+        Severity::Error
+    );
+}
+
+#[test]
+fn severity_invalid() {
+    let err = parse("\"loud\"").unwrap_err().to_string();
+    assert!(err.contains("loud"), "{}", err);
+    assert!(err.contains("severity"), "{}", err);
+    let err = parse("9").unwrap_err().to_string();
+    assert!(err.contains('9'), "{}", err);
+    assert!(parse("0").is_err());
+    assert!(parse("-1").is_err());
+    assert!(parse("\"7\"").is_err());
+}
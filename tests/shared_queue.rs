@@ -0,0 +1,43 @@
+// SharedLogQueue
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{log, LogQueue, Severity, SharedLogQueue};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn shared_queue_tasks() {
+    let queue = SharedLogQueue::new();
+    let tasks: Vec<_> = (0..10)
+        .map(|task| {
+            let queue = queue.clone();
+            tokio::spawn(async move {
+                for n in 0..100 {
+                    log!(queue, Severity::Info, text: "entry", task: task, n: n);
+                }
+            })
+        })
+        .collect();
+    for t in tasks {
+        t.await.unwrap();
+    }
+    assert_eq!(queue.len(), 1000);
+    let entries = queue.take();
+    assert!(queue.is_empty());
+    for task in 0..10 {
+        let task = task.to_string();
+        let count = entries
+            .iter()
+            .filter(|e| e.custom_fields.get("task") == Some(&task))
+            .count();
+        assert_eq!(count, 100);
+    }
+}
+
+#[test]
+fn shared_queue_from_arc() {
+    let arc = Arc::new(Mutex::new(LogQueue::new()));
+    let queue = SharedLogQueue::from(arc.clone());
+    log!(queue, Severity::Warning, text: "hello");
+    assert_eq!(arc.lock().unwrap().len(), 1);
+    assert!(Arc::ptr_eq(&queue.inner(), &arc));
+}
@@ -0,0 +1,140 @@
+// Logger::shutdown, BatchLogger, and LoggerShutdownGuard
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{BatchLogger, LogEntry, Logger, LoggerShutdownGuard, MockLogger, VecLogger};
+
+fn entries(n: usize) -> Vec<LogEntry> {
+    vec![LogEntry::default(); n]
+}
+
+#[tokio::test]
+async fn batch_logger_batches() {
+    let (inner, handle) = VecLogger::new();
+    let logger = BatchLogger::new(inner, 3);
+    logger.send("a", entries(2)).await.unwrap();
+    logger.send("b", entries(1)).await.unwrap();
+    assert!(handle.entries().is_empty());
+    assert_eq!(logger.buffered(), 3);
+
+    // "a" reaches batch size
+    logger.send("a", entries(1)).await.unwrap();
+    assert_eq!(handle.entries().len(), 3);
+    assert!(handle.entries().iter().all(|(sub, _)| sub == "a"));
+    assert_eq!(logger.buffered(), 1);
+
+    logger.shutdown().await.unwrap();
+    assert_eq!(logger.buffered(), 0);
+    let entries = handle.entries();
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries[3].0, "b");
+}
+
+#[tokio::test]
+async fn batch_logger_keeps_unsent() {
+    let mock = MockLogger::new();
+    let logger = BatchLogger::new(Box::new(mock.clone()), 100);
+    logger.send("a", entries(2)).await.unwrap();
+    logger.send("b", entries(3)).await.unwrap();
+    logger.send("c", entries(4)).await.unwrap();
+
+    // "a" fails: "b" and "c" are still sent, and "a" stays buffered
+    mock.fail_next(1);
+    assert!(logger.flush().await.is_err());
+    assert_eq!(mock.captured_entries().len(), 7);
+    assert_eq!(logger.buffered(), 2);
+
+    // all fail: nothing is lost, and the inner logger isn't shut down
+    logger.send("b", entries(1)).await.unwrap();
+    mock.fail_next(2);
+    assert!(logger.shutdown().await.is_err());
+    assert_eq!(logger.buffered(), 3);
+
+    logger.shutdown().await.unwrap();
+    assert_eq!(logger.buffered(), 0);
+    assert_eq!(mock.captured_entries().len(), 10);
+}
+
+#[tokio::test]
+async fn batch_logger_send_failure_keeps_batch() {
+    let mock = MockLogger::new();
+    let logger = BatchLogger::new(Box::new(mock.clone()), 2);
+    let first = LogEntry {
+        text: "first".to_string(),
+        ..Default::default()
+    };
+    let second = LogEntry {
+        text: "second".to_string(),
+        ..Default::default()
+    };
+    mock.fail_next(1);
+    // entries are buffered, so send succeeds
+    logger.send("a", vec![first, second]).await.unwrap();
+    assert_eq!(logger.buffered(), 2);
+
+    // retried, in order, with the next batch
+    let third = LogEntry {
+        text: "third".to_string(),
+        ..Default::default()
+    };
+    logger.send("a", vec![third]).await.unwrap();
+    let texts: Vec<String> = mock
+        .captured_entries()
+        .into_iter()
+        .map(|e| e.text)
+        .collect();
+    assert_eq!(texts, vec!["first", "second", "third"]);
+}
+
+#[tokio::test]
+async fn batch_logger_max_buffered() {
+    let mock = MockLogger::new();
+    let logger = BatchLogger::new(Box::new(mock.clone()), 3).with_max_buffered(4);
+    let entry = |text: &str, timestamp: u64| LogEntry {
+        text: text.to_string(),
+        timestamp,
+        ..Default::default()
+    };
+    logger.send("a", vec![entry("a1", 1)]).await.unwrap();
+    logger.send("b", vec![entry("b2", 2)]).await.unwrap();
+    logger.send("a", vec![entry("a3", 3)]).await.unwrap();
+    logger.send("b", vec![entry("b4", 4)]).await.unwrap();
+    assert_eq!(logger.dropped(), 0);
+
+    // the oldest entries are dropped past the limit
+    logger.send("a", vec![entry("a5", 5)]).await.unwrap();
+    assert_eq!(logger.buffered(), 4);
+    assert_eq!(logger.dropped(), 1);
+    // a failed batch is returned to the buffer, within the limit
+    mock.fail_next(1);
+    logger
+        .send("b", vec![entry("b6", 6), entry("b7", 7)])
+        .await
+        .unwrap();
+    assert_eq!(logger.buffered(), 4);
+    assert_eq!(logger.dropped(), 3);
+
+    logger.flush().await.unwrap();
+    let mut texts: Vec<String> = mock
+        .captured_entries()
+        .into_iter()
+        .map(|e| e.text)
+        .collect();
+    texts.sort();
+    assert_eq!(texts, vec!["a5", "b4", "b6", "b7"]);
+}
+
+#[tokio::test]
+async fn shutdown_guard() {
+    let (inner, handle) = VecLogger::new();
+    let logger = LoggerShutdownGuard::new(BatchLogger::new(inner, 100));
+    logger.send("sub", entries(5)).await.unwrap();
+    assert!(handle.entries().is_empty());
+    logger.drop_async().await.unwrap();
+    assert_eq!(handle.entries().len(), 5);
+}
+
+#[tokio::test]
+async fn default_shutdown() {
+    let (logger, _) = VecLogger::new();
+    assert!(logger.shutdown().await.is_ok());
+}
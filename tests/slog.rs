@@ -0,0 +1,46 @@
+// ServiceLoggingDrain
+//
+#![cfg(feature = "slog")]
+use service_logging::{LogQueue, ServiceLoggingDrain, Severity};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn slog_drain_entry() {
+    let queue = Arc::new(Mutex::new(LogQueue::default()));
+    let logger = slog::Logger::root(
+        ServiceLoggingDrain::new(queue.clone()),
+        slog::o!("service" => "api", "key" => "overridden"),
+    );
+    slog::info!(logger, "hello"; "key" => "value", "count" => 3);
+    slog::crit!(logger, #"db", "connection lost");
+
+    let entries = queue.lock().unwrap().take();
+    assert_eq!(entries.len(), 2);
+    let e = &entries[0];
+    assert_eq!(e.severity, Severity::Info);
+    assert_eq!(e.text, "hello");
+    assert_eq!(e.category, None);
+    assert_eq!(
+        e.custom_fields.get("key").map(String::as_str),
+        Some("value")
+    );
+    assert_eq!(e.custom_fields.get("count").map(String::as_str), Some("3"));
+    assert_eq!(
+        e.custom_fields.get("service").map(String::as_str),
+        Some("api")
+    );
+    assert_eq!(e.source_file.as_deref(), Some(file!()));
+    assert!(e.timestamp > 0);
+
+    assert_eq!(entries[1].severity, Severity::Critical);
+    assert_eq!(entries[1].text, "connection lost");
+    assert_eq!(entries[1].category.as_deref(), Some("db"));
+}
+
+#[test]
+fn slog_levels() {
+    assert_eq!(Severity::from(slog::Level::Trace), Severity::Debug);
+    assert_eq!(Severity::from(slog::Level::Debug), Severity::Verbose);
+    assert_eq!(Severity::from(slog::Level::Warning), Severity::Warning);
+    assert_eq!(Severity::from(slog::Level::Error), Severity::Error);
+}
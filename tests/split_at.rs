@@ -0,0 +1,37 @@
+// LogQueue::split_at
+//
+use service_logging::{LogEntry, LogQueue};
+
+fn queue() -> LogQueue {
+    LogQueue::from((0..6).map(LogEntry::with_timestamp).collect())
+}
+
+fn timestamps(mut lq: LogQueue) -> Vec<u64> {
+    lq.take().iter().map(|e| e.timestamp).collect()
+}
+
+#[test]
+fn split_at() {
+    let (first, second) = queue().split_at(2);
+    assert_eq!(first.len() + second.len(), 6);
+    assert_eq!(timestamps(first), vec![0, 1]);
+    assert_eq!(timestamps(second), vec![2, 3, 4, 5]);
+}
+
+#[test]
+fn split_at_ends() {
+    let (first, second) = queue().split_at(0);
+    assert!(first.is_empty());
+    assert_eq!(second.len(), 6);
+
+    let (first, second) = queue().split_at(6);
+    assert_eq!(first.len(), 6);
+    assert!(second.is_empty());
+
+    let (first, second) = queue().split_at(100);
+    assert_eq!(timestamps(first), timestamps(queue()));
+    assert!(second.is_empty());
+
+    let (first, second) = LogQueue::default().split_at(1);
+    assert!(first.is_empty() && second.is_empty());
+}
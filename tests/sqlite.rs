@@ -0,0 +1,183 @@
+// SqliteLogger
+//
+#![cfg(feature = "sqlite")]
+use service_logging::{LogEntry, Logger, Severity, SqliteConfig, SqliteLogger};
+use std::path::PathBuf;
+
+fn db_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "service-logging-{}-{}.db",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn entry(timestamp: u64, severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        timestamp,
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn sqlite_insert_and_query() {
+    let path = db_path("query");
+    let logger = SqliteLogger::new(SqliteConfig {
+        db_path: path.clone(),
+        table_name: "logs".to_string(),
+        max_rows: None,
+    })
+    .expect("open");
+
+    let mut detailed = entry(3000, Severity::Error, "disk full");
+    detailed.category = Some("storage".to_string());
+    detailed.source_line = Some(42);
+    detailed.custom_fields.insert("disk".into(), "sda".into());
+    detailed.add_tag("urgent");
+    detailed.add_metric("bytes", 0.0);
+    detailed = detailed.with_trace("t1", "s1");
+    detailed.hostname = Some("web-1".to_string());
+    logger
+        .send(
+            "api",
+            vec![
+                entry(1000, Severity::Info, "started"),
+                entry(2000, Severity::Warning, "slow"),
+                detailed,
+            ],
+        )
+        .await
+        .expect("send");
+
+    let all = logger.query(Severity::Debug, 0).expect("query");
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].text, "started");
+
+    let warnings = logger.query(Severity::Warning, 0).expect("query");
+    let texts: Vec<&str> = warnings.iter().map(|e| e.text.as_str()).collect();
+    assert_eq!(texts, vec!["slow", "disk full"]);
+
+    let recent = logger.query(Severity::Debug, 2500).expect("query");
+    assert_eq!(recent.len(), 1);
+    let e = &recent[0];
+    assert_eq!(e.severity, Severity::Error);
+    assert_eq!(e.category.as_deref(), Some("storage"));
+    assert_eq!(e.source_line, Some(42));
+    assert_eq!(e.custom_fields.get("disk").map(String::as_str), Some("sda"));
+    assert_eq!(e.tags, vec!["urgent".to_string()]);
+    assert_eq!(e.metrics.get("bytes"), Some(&0.0));
+    assert_eq!(e.trace_id.as_deref(), Some("t1"));
+    assert_eq!(e.span_id.as_deref(), Some("s1"));
+    assert_eq!(e.hostname.as_deref(), Some("web-1"));
+    assert_eq!(all[0].hostname, None);
+
+    // entries persist after reopening
+    drop(logger);
+    let reopened = SqliteLogger::new(SqliteConfig {
+        db_path: path.clone(),
+        table_name: "logs".to_string(),
+        max_rows: None,
+    })
+    .expect("reopen");
+    assert_eq!(reopened.query(Severity::Debug, 0).unwrap().len(), 3);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn sqlite_adds_hostname_column() {
+    // table created by a version without the hostname column
+    let path = db_path("migrate");
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE logs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            timestamp_us INTEGER,
+            severity INTEGER NOT NULL,
+            text TEXT NOT NULL,
+            category TEXT,
+            class_name TEXT,
+            method_name TEXT,
+            thread_id TEXT,
+            source_file TEXT,
+            source_line INTEGER,
+            source_module TEXT,
+            custom_fields TEXT NOT NULL,
+            tags TEXT NOT NULL,
+            trace_id TEXT,
+            span_id TEXT,
+            environment TEXT,
+            app_version TEXT,
+            metrics TEXT NOT NULL,
+            subsystem TEXT NOT NULL
+        );
+        INSERT INTO logs (timestamp, severity, text, custom_fields, tags, metrics, subsystem)
+            VALUES (1000, 3, 'old', '{}', '[]', '{}', 'api');",
+    )
+    .unwrap();
+    drop(conn);
+
+    let logger = SqliteLogger::new(SqliteConfig {
+        db_path: path.clone(),
+        table_name: "logs".to_string(),
+        max_rows: None,
+    })
+    .expect("open");
+    let mut e = entry(2000, Severity::Info, "new");
+    e.hostname = Some("web-1".to_string());
+    logger.send("api", vec![e]).await.expect("send");
+    let all = logger.query(Severity::Debug, 0).expect("query");
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].text, "old");
+    assert_eq!(all[0].hostname, None);
+    assert_eq!(all[1].hostname.as_deref(), Some("web-1"));
+
+    // reopening doesn't add the column again
+    drop(logger);
+    SqliteLogger::new(SqliteConfig {
+        db_path: path.clone(),
+        table_name: "logs".to_string(),
+        max_rows: None,
+    })
+    .expect("reopen");
+    let _ = std::fs::remove_file(&path);
+}
+
+#[tokio::test]
+async fn sqlite_max_rows() {
+    let path = db_path("max_rows");
+    let logger = SqliteLogger::new(SqliteConfig {
+        db_path: path.clone(),
+        table_name: "logs".to_string(),
+        max_rows: Some(3),
+    })
+    .expect("open");
+    for n in 0..5u64 {
+        logger
+            .send("api", vec![entry(n, Severity::Info, &n.to_string())])
+            .await
+            .expect("send");
+    }
+    let texts: Vec<String> = logger
+        .query(Severity::Debug, 0)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.text)
+        .collect();
+    assert_eq!(texts, vec!["2", "3", "4"]);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn sqlite_invalid_table_name() {
+    assert!(SqliteLogger::new(SqliteConfig {
+        db_path: db_path("invalid"),
+        table_name: "logs; DROP TABLE x".to_string(),
+        max_rows: None,
+    })
+    .is_err());
+}
@@ -0,0 +1,36 @@
+// CoralogixLogger::stats
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+mod common;
+use common::MockServer;
+use service_logging::{CoralogixConfig, CoralogixLogger, LogEntry, Logger, LoggerStats};
+
+#[tokio::test]
+async fn coralogix_stats() {
+    let server = MockServer::with_statuses(vec![200, 500, 200, 503]);
+    let endpoint = format!("{}/api/v1/logs", server.url);
+    let logger = CoralogixLogger::new(CoralogixConfig {
+        api_key: "key",
+        application_name: "app",
+        endpoint: &endpoint,
+        ..Default::default()
+    })
+    .expect("init");
+    assert_eq!(logger.stats(), LoggerStats::default());
+    let handle = logger.stats_handle();
+    let logger: Box<dyn Logger + Send> = Box::new(logger);
+
+    let batch = |n: usize| vec![LogEntry::default(); n];
+    assert!(logger.send("sub", batch(2)).await.is_ok());
+    assert!(logger.send("sub", batch(3)).await.is_err());
+    assert!(logger.send("sub", batch(4)).await.is_ok());
+    assert!(logger.send("sub", batch(5)).await.is_err());
+    // empty batches aren't sent
+    assert!(logger.send("sub", Vec::new()).await.is_ok());
+
+    let stats = handle.stats();
+    assert_eq!(stats.entries_sent, 6);
+    assert_eq!(stats.send_errors, 2);
+    assert!(stats.last_error_ms > 0);
+    assert_eq!(server.requests().len(), 4);
+}
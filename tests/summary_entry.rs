@@ -0,0 +1,58 @@
+// LogQueue::to_summary_entry
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use serde_json::{json, Value};
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn entry(timestamp: u64, severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        timestamp,
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn summary_entry() {
+    let lq = LogQueue::from(vec![
+        entry(30, Severity::Debug, "a"),
+        entry(10, Severity::Debug, "b"),
+        entry(50, Severity::Error, "first error"),
+        entry(20, Severity::Info, "c"),
+        entry(40, Severity::Error, "second error"),
+    ]);
+    let summary = lq.to_summary_entry(Severity::Warning);
+    assert_eq!(summary.severity, Severity::Warning);
+    let text: Value = serde_json::from_str(&summary.text).expect("json");
+    assert_eq!(
+        text,
+        json!({
+            "total_entries": 5,
+            "by_severity": {
+                "debug": 2, "verbose": 0, "info": 1, "warning": 0, "error": 2, "critical": 0,
+            },
+            "oldest_timestamp": 10,
+            "newest_timestamp": 50,
+            "highest_severity_text": "first error",
+        })
+    );
+    let counted: u64 = text["by_severity"]
+        .as_object()
+        .unwrap()
+        .values()
+        .map(|v| v.as_u64().unwrap())
+        .sum();
+    assert_eq!(counted as usize, lq.len());
+}
+
+#[test]
+fn empty_summary() {
+    let summary = LogQueue::default().to_summary_entry(Severity::Info);
+    let text: Value = serde_json::from_str(&summary.text).expect("json");
+    assert_eq!(text["total_entries"], 0);
+    assert_eq!(text["by_severity"]["debug"], 0);
+    assert!(text["oldest_timestamp"].is_null());
+    assert!(text["newest_timestamp"].is_null());
+    assert!(text["highest_severity_text"].is_null());
+}
@@ -0,0 +1,59 @@
+// syslog (RFC 5424) serialization
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use regex::Regex;
+use service_logging::{ConsoleLogger, LogEntry, Severity};
+
+// PRI VERSION SP TIMESTAMP SP HOSTNAME SP APP-NAME SP PROCID SP MSGID SP STRUCTURED-DATA [SP MSG]
+const HEADER: &str = r#"^<(\d{1,3})>1 (\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d{1,6})?Z) ([!-~]{1,255}) ([!-~]{1,48}) ([!-~]{1,128}) ([!-~]{1,32}) (-|\[(?:[^\]\\]|\\.)*\])( (.*))?$"#;
+
+#[test]
+fn syslog_header() {
+    let mut entry = LogEntry {
+        timestamp: 1_600_000_000_123,
+        severity: Severity::Error,
+        text: "disk failed".to_string(),
+        category: Some("storage".to_string()),
+        ..Default::default()
+    };
+    entry.custom_fields.insert("device".into(), "sd\"a]".into());
+
+    let line = entry.to_syslog_line("host1", "my app", "42");
+    let re = Regex::new(HEADER).unwrap();
+    let caps = re.captures(&line).expect("valid syslog line");
+    // facility user (1) * 8 + error (3)
+    assert_eq!(&caps[1], "11");
+    assert_eq!(&caps[2], "2020-09-13T12:26:40.123Z");
+    assert_eq!(&caps[4], "host1");
+    assert_eq!(&caps[5], "myapp");
+    assert_eq!(&caps[6], "42");
+    assert_eq!(&caps[7], "storage");
+    assert_eq!(&caps[8], r#"[fields@32473 device="sd\"a\]"]"#);
+    assert_eq!(&caps[10], "disk failed");
+}
+
+#[test]
+fn syslog_nil_values() {
+    let entry = LogEntry {
+        severity: Severity::Info,
+        ..Default::default()
+    };
+    let line = entry.to_syslog_line("", "app", "");
+    let re = Regex::new(HEADER).unwrap();
+    let caps = re.captures(&line).expect("valid syslog line");
+    assert_eq!(&caps[1], "14");
+    assert_eq!(&caps[4], "-");
+    assert_eq!(&caps[6], "-");
+    assert_eq!(&caps[7], "-");
+    assert_eq!(&caps[8], "-");
+    assert!(caps.get(10).is_none());
+}
+
+#[tokio::test]
+async fn syslog_console_logger() {
+    let logger = ConsoleLogger::init_syslog("host1".into(), "app".into());
+    logger
+        .send("test_syslog", vec![LogEntry::default()])
+        .await
+        .expect("send");
+}
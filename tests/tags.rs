@@ -0,0 +1,24 @@
+// LogEntry tags
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{make_log_entry, LogEntry, Severity};
+
+#[test]
+fn tags_from_macro() {
+    let e = make_log_entry!(Severity::Info, text: "hello", tags: "auth, login,");
+    assert_eq!(e.tags, vec!["auth", "login"]);
+    let json: serde_json::Value = serde_json::from_str(&e.to_json().unwrap()).unwrap();
+    assert_eq!(json["tags"], serde_json::json!(["auth", "login"]));
+}
+
+#[test]
+fn tags_builder_and_skip_empty() {
+    let mut e = LogEntry {
+        severity: Severity::Info,
+        ..Default::default()
+    };
+    assert!(!e.to_json().unwrap().contains("tags"));
+    e.add_tag("one").add_tag(String::from("two"));
+    assert_eq!(e.tags, vec!["one", "two"]);
+    assert!(e.to_json().unwrap().contains(r#""tags":["one","two"]"#));
+}
@@ -0,0 +1,54 @@
+// LogQueue::take_sorted_by_severity
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn entry(severity: Severity, text: &str) -> LogEntry {
+    LogEntry {
+        severity,
+        text: text.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sorted_descending_and_stable() {
+    let mut lq = LogQueue::from(vec![
+        entry(Severity::Debug, "debug 1"),
+        entry(Severity::Error, "error 1"),
+        entry(Severity::Info, "info"),
+        entry(Severity::Critical, "critical 1"),
+        entry(Severity::Debug, "debug 2"),
+        entry(Severity::Warning, "warning"),
+        entry(Severity::Critical, "critical 2"),
+        entry(Severity::Error, "error 2"),
+        entry(Severity::Verbose, "verbose"),
+    ]);
+    let len = lq.len();
+    let entries = lq.take_sorted_by_severity();
+    assert!(lq.is_empty());
+    assert_eq!(entries.len(), len);
+
+    let texts: Vec<&str> = entries.iter().map(|e| e.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "critical 1",
+            "critical 2",
+            "error 1",
+            "error 2",
+            "warning",
+            "info",
+            "verbose",
+            "debug 1",
+            "debug 2",
+        ]
+    );
+    assert_eq!(entries[0].severity, Severity::Critical);
+    assert_eq!(entries[len - 1].severity, Severity::Debug);
+}
+
+#[test]
+fn empty_queue() {
+    let mut lq = LogQueue::new();
+    assert!(lq.take_sorted_by_severity().is_empty());
+}
@@ -0,0 +1,18 @@
+// current_time_millis and current_time_micros
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{current_time_micros, current_time_millis};
+
+#[test]
+fn current_time() {
+    let t1 = current_time_millis();
+    let t2 = current_time_millis();
+    assert!(t1 > 1_700_000_000_000);
+    assert!(t2 >= t1);
+
+    let us1 = current_time_micros();
+    let us2 = current_time_micros();
+    assert!(us1 > 1_700_000_000_000_000);
+    assert!(us2 >= us1);
+    assert!(us1 / 1000 >= t1);
+}
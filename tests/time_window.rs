@@ -0,0 +1,60 @@
+// LogQueue::time_window_entries, time_window_entries_sorted, and entries_since
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{LogEntry, LogQueue};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn queue(timestamps: &[u64]) -> LogQueue {
+    LogQueue::from(
+        timestamps
+            .iter()
+            .map(|timestamp| LogEntry {
+                timestamp: *timestamp,
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+fn timestamps(entries: Vec<&LogEntry>) -> Vec<u64> {
+    entries.iter().map(|e| e.timestamp).collect()
+}
+
+#[test]
+fn time_window_sorted() {
+    let lq = queue(&[10, 20, 20, 30, 40]);
+    assert!(lq.is_sorted());
+    for (start, end, expected) in [
+        (20, 40, vec![20, 20, 30]),
+        (0, 10, vec![]),
+        (0, 11, vec![10]),
+        (40, 41, vec![40]),
+        (41, 100, vec![]),
+        (30, 20, vec![]),
+    ] {
+        assert_eq!(timestamps(lq.time_window_entries(start, end)), expected);
+        assert_eq!(
+            timestamps(lq.time_window_entries_sorted(start, end)),
+            expected
+        );
+    }
+}
+
+#[test]
+fn time_window_unsorted() {
+    let lq = queue(&[30, 10, 40, 20]);
+    assert!(!lq.is_sorted());
+    assert_eq!(timestamps(lq.time_window_entries(20, 40)), vec![30, 20]);
+    assert_eq!(timestamps(lq.time_window_entries(40, 50)), vec![40]);
+}
+
+#[test]
+fn entries_since() {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let lq = queue(&[now - 120_000, now - 30_000, now - 1_000]);
+    assert_eq!(lq.entries_since(Duration::from_secs(60)).len(), 2);
+    assert_eq!(lq.entries_since(Duration::from_secs(600)).len(), 3);
+}
@@ -0,0 +1,38 @@
+// explicit timestamps with LogEntry::with_timestamp, set_timestamp, and make_log_entry!
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{make_log_entry, LogEntry, Severity};
+
+const TS: u64 = 1_600_000_000_123;
+
+#[test]
+fn with_timestamp() {
+    let e = LogEntry::with_timestamp(TS);
+    assert_eq!(e.timestamp, TS);
+    let json = serde_json::to_string(&e).unwrap();
+    assert!(json.contains(&format!("\"timestamp\":{}", TS)), "{}", json);
+    let parsed: LogEntry = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.timestamp, TS);
+
+    let mut e = LogEntry {
+        timestamp_us: Some(5),
+        ..Default::default()
+    };
+    e.set_timestamp(TS).add_tag("replayed");
+    assert_eq!(e.timestamp, TS);
+    assert_eq!(e.timestamp_us, None);
+}
+
+#[test]
+fn macro_timestamp() {
+    let e = make_log_entry!(Severity::Info, text: "replayed", timestamp: TS);
+    assert_eq!(e.timestamp, TS);
+    assert!(e.custom_fields.is_empty());
+    let parsed: LogEntry = serde_json::from_str(&e.to_json().unwrap()).unwrap();
+    assert_eq!(parsed.timestamp, TS);
+
+    // values that aren't integers are kept as fields
+    let e = make_log_entry!(Severity::Info, text: "x", timestamp: "yesterday");
+    assert_ne!(e.timestamp, TS);
+    assert_eq!(e.custom_fields.get("timestamp").unwrap(), "yesterday");
+}
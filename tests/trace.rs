@@ -0,0 +1,21 @@
+// trace and span ids
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{make_log_entry, LogEntry, Severity};
+
+#[test]
+fn trace_fields_top_level() {
+    let e = make_log_entry!(Severity::Info, trace_id: "abc", span_id: "def", user: "bob");
+    let json: serde_json::Value = serde_json::from_str(&e.to_json().unwrap()).unwrap();
+    assert_eq!(json["traceId"], "abc");
+    assert_eq!(json["spanId"], "def");
+    assert_eq!(json["text"], r#"{"user":"bob"}"#);
+}
+
+#[test]
+fn with_trace_builder() {
+    let e = LogEntry::default().with_trace("abc", String::from("def"));
+    assert_eq!(e.trace_id.as_deref(), Some("abc"));
+    assert_eq!(e.span_id.as_deref(), Some("def"));
+    assert!(!LogEntry::default().to_json().unwrap().contains("traceId"));
+}
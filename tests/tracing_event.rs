@@ -0,0 +1,119 @@
+// conversion from tracing::Event (feature "tracing")
+//
+#![cfg(feature = "tracing")]
+use service_logging::{LogEntry, Severity};
+use std::cell::RefCell;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex, Once,
+};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata};
+use tracing_core::span::Current;
+
+thread_local! {
+    static ENTRIES: RefCell<Vec<LogEntry>> = const { RefCell::new(Vec::new()) };
+    static STACK: RefCell<Vec<Id>> = const { RefCell::new(Vec::new()) };
+}
+
+// subscriber that converts events to entries, and tracks entered spans, for the current thread.
+// It is installed as the global default: the current span isn't available
+// inside a scoped (with_default) subscriber's callbacks
+#[derive(Default)]
+struct Capture {
+    spans: Mutex<Vec<(Id, &'static Metadata<'static>)>>,
+    next_id: AtomicU64,
+}
+
+impl tracing::Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let id = Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1);
+        self.spans
+            .lock()
+            .unwrap()
+            .push((id.clone(), attrs.metadata()));
+        id
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        ENTRIES.with(|e| e.borrow_mut().push(LogEntry::from(event)));
+    }
+
+    fn enter(&self, id: &Id) {
+        STACK.with(|s| s.borrow_mut().push(id.clone()));
+    }
+
+    fn exit(&self, _: &Id) {
+        STACK.with(|s| s.borrow_mut().pop());
+    }
+
+    fn current_span(&self) -> Current {
+        let spans = self.spans.lock().unwrap();
+        STACK.with(|s| {
+            match s
+                .borrow()
+                .last()
+                .and_then(|id| spans.iter().find(|(span_id, _)| span_id == id))
+            {
+                Some((id, meta)) => Current::new(id.clone(), meta),
+                None => Current::none(),
+            }
+        })
+    }
+}
+
+fn capture(f: impl FnOnce()) -> Vec<LogEntry> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| tracing::subscriber::set_global_default(Capture::default()).unwrap());
+    f();
+    ENTRIES.with(|e| e.take())
+}
+
+#[test]
+fn from_event() {
+    let entries = capture(|| {
+        tracing::info!(key = "value", count = 3, "message {}", 1);
+    });
+    assert_eq!(entries.len(), 1);
+    let e = &entries[0];
+    assert_eq!(e.severity, Severity::Info);
+    assert_eq!(e.text, "message 1");
+    assert_eq!(
+        e.custom_fields.get("key").map(String::as_str),
+        Some("value")
+    );
+    assert_eq!(e.custom_fields.get("count").map(String::as_str), Some("3"));
+    assert!(!e.custom_fields.contains_key("message"));
+    assert!(e.category.is_none());
+    assert_eq!(e.source_file.as_deref(), Some(file!()));
+    assert!(e.source_line.is_some());
+}
+
+#[test]
+fn span_name_is_category() {
+    let entries = capture(|| {
+        let span = tracing::info_span!("checkout");
+        let _enter = span.enter();
+        tracing::warn!("payment declined");
+    });
+    assert_eq!(entries[0].severity, Severity::Warning);
+    assert_eq!(entries[0].text, "payment declined");
+    assert_eq!(entries[0].category.as_deref(), Some("checkout"));
+}
+
+#[test]
+fn levels() {
+    assert_eq!(Severity::from(tracing::Level::ERROR), Severity::Error);
+    assert_eq!(Severity::from(tracing::Level::WARN), Severity::Warning);
+    assert_eq!(Severity::from(tracing::Level::INFO), Severity::Info);
+    assert_eq!(Severity::from(tracing::Level::DEBUG), Severity::Verbose);
+    assert_eq!(Severity::from(tracing::Level::TRACE), Severity::Debug);
+}
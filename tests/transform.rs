@@ -0,0 +1,44 @@
+// LogQueue::apply_transform and map_entries
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn queue() -> LogQueue {
+    LogQueue::from(
+        (0..3)
+            .map(|n| LogEntry {
+                timestamp: n,
+                severity: Severity::Info,
+                text: format!("msg {}", n),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn apply_transform() {
+    let mut lq = queue();
+    lq.apply_transform(|e| e.environment = Some("prod".to_string()));
+    lq.apply_transform(|e| {
+        e.add_tag("stamped");
+    });
+    assert_eq!(lq.len(), 3);
+    for e in lq.take() {
+        assert_eq!(e.environment.as_deref(), Some("prod"));
+        assert_eq!(e.tags, vec!["stamped".to_string()]);
+    }
+}
+
+#[test]
+fn map_entries() {
+    let mut identity = queue().map_entries(|e| e);
+    let json = |entries: Vec<LogEntry>| serde_json::to_string(&entries).unwrap();
+    assert_eq!(json(identity.take()), json(queue().take()));
+
+    let mut upper = queue().map_entries(|e| LogEntry {
+        text: e.text.to_uppercase(),
+        ..e
+    });
+    let texts: Vec<String> = upper.take().into_iter().map(|e| e.text).collect();
+    assert_eq!(texts, vec!["MSG 0", "MSG 1", "MSG 2"]);
+}
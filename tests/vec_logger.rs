@@ -0,0 +1,27 @@
+// VecLogger captures entries in memory
+//
+#![cfg(not(all(feature = "no-std", not(feature = "std"))))]
+use service_logging::{log, LogQueue, Severity, VecLogger};
+
+#[tokio::test]
+async fn vec_logger_captures_entries() {
+    let (logger, handle) = VecLogger::new();
+    let mut log_queue = LogQueue::default();
+    log!(log_queue, Severity::Info, text: "one");
+    log!(log_queue, Severity::Error, text: "two");
+    logger.send("first", log_queue.take()).await.expect("send");
+    log!(log_queue, Severity::Debug, text: "three");
+    logger.send("second", log_queue.take()).await.expect("send");
+
+    let entries = handle.entries();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].0, "first");
+    assert_eq!(entries[0].1.text, "one");
+    assert_eq!(entries[1].1.severity, Severity::Error);
+    assert_eq!(entries[2].0, "second");
+    assert!(handle.has_entry_matching(|e| e.text == "three"));
+    assert!(!handle.has_entry_matching(|e| e.text == "four"));
+
+    handle.clear();
+    assert!(handle.entries().is_empty());
+}
@@ -0,0 +1,166 @@
+// WebhookLogger
+//
+#![cfg(feature = "webhook")]
+mod common;
+use common::MockServer;
+use service_logging::{
+    EcsSerializer, LogEntry, LogEntrySerializer, Logger, ServiceLoggingError, WebhookBodyFormat,
+    WebhookConfig, WebhookLogger,
+};
+use std::sync::Arc;
+
+fn logger(server: &MockServer, format: WebhookBodyFormat) -> Box<dyn Logger + Send> {
+    WebhookLogger::init(WebhookConfig {
+        url: format!("{}/hook", server.url),
+        headers: vec![("Authorization".to_string(), "Bearer abc".to_string())],
+        body_template: format,
+        ..Default::default()
+    })
+    .expect("init")
+}
+
+fn entries() -> Vec<LogEntry> {
+    ["one", "two"]
+        .iter()
+        .map(|text| LogEntry {
+            text: text.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn webhook_json_array() {
+    let server = MockServer::start();
+    logger(&server, WebhookBodyFormat::JsonArray)
+        .send("sub", entries())
+        .await
+        .expect("send");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/hook");
+    assert_eq!(requests[0].header("authorization"), Some("Bearer abc"));
+    assert_eq!(requests[0].header("content-type"), Some("application/json"));
+    let body = requests[0].json();
+    let arr = body.as_array().expect("array");
+    assert_eq!(arr.len(), 2);
+    assert_eq!(arr[1]["text"], "two");
+}
+
+#[tokio::test]
+async fn webhook_json_lines() {
+    let server = MockServer::start();
+    logger(&server, WebhookBodyFormat::JsonLines)
+        .send("sub", entries())
+        .await
+        .expect("send");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].header("content-type"),
+        Some("application/x-ndjson")
+    );
+    let body = requests[0].body_string();
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("json line"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["text"], "one");
+}
+
+#[tokio::test]
+async fn webhook_single_entry() {
+    let server = MockServer::start();
+    logger(&server, WebhookBodyFormat::SingleEntry)
+        .send("sub", entries())
+        .await
+        .expect("send");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    for (req, text) in requests.iter().zip(["one", "two"].iter()) {
+        assert_eq!(req.header("content-type"), Some("application/json"));
+        assert_eq!(req.header("authorization"), Some("Bearer abc"));
+        assert_eq!(req.json()["text"], *text);
+    }
+}
+
+#[tokio::test]
+async fn webhook_serializer() {
+    let server = MockServer::start();
+    WebhookLogger::init(WebhookConfig {
+        url: format!("{}/hook", server.url),
+        body_template: WebhookBodyFormat::JsonLines,
+        serializer: Some(Arc::new(EcsSerializer)),
+        ..Default::default()
+    })
+    .expect("init")
+    .send("sub", entries())
+    .await
+    .expect("send");
+    let body = server.requests()[0].body_string();
+    let lines: Vec<serde_json::Value> = body
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("json line"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1]["message"], "two");
+    assert!(lines[1].get("text").is_none());
+}
+
+// fails to serialize entries with text "bad"
+#[derive(Debug)]
+struct FailingSerializer;
+
+impl LogEntrySerializer for FailingSerializer {
+    fn serialize(
+        &self,
+        entry: &LogEntry,
+        backend: &str,
+    ) -> Result<serde_json::Value, ServiceLoggingError> {
+        if entry.text == "bad" {
+            return Err(ServiceLoggingError::Other(format!(
+                "can't serialize for {}",
+                backend
+            )));
+        }
+        Ok(serde_json::json!({ "msg": entry.text }))
+    }
+}
+
+#[tokio::test]
+async fn webhook_payload_too_large() {
+    let server = MockServer::with_statuses(vec![413]);
+    let err = logger(&server, WebhookBodyFormat::JsonArray)
+        .send("sub", entries())
+        .await
+        .unwrap_err();
+    let sent = server.requests()[0].body_string().len();
+    assert!(matches!(
+        err,
+        ServiceLoggingError::PayloadTooLarge { size, limit: 0, .. } if size == sent
+    ));
+}
+
+#[tokio::test]
+async fn webhook_serializer_error() {
+    let server = MockServer::start();
+    let logger = WebhookLogger::init(WebhookConfig {
+        url: format!("{}/hook", server.url),
+        body_template: WebhookBodyFormat::JsonArray,
+        serializer: Some(Arc::new(FailingSerializer)),
+        ..Default::default()
+    })
+    .expect("init");
+    let bad = LogEntry {
+        text: "bad".to_string(),
+        ..Default::default()
+    };
+    let err = logger
+        .send("sub", vec![LogEntry::default(), bad])
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "can't serialize for webhook");
+    // nothing is sent
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,75 @@
+// LogQueue::windows and LogQueue::chunks
+//
+use service_logging::{LogEntry, LogQueue, Severity};
+
+fn queue(severities: &[Severity]) -> LogQueue {
+    LogQueue::from(
+        severities
+            .iter()
+            .map(|severity| LogEntry {
+                severity: severity.clone(),
+                ..Default::default()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn windows() {
+    use Severity::{Error, Info};
+    let mut lq = queue(&[Info, Error, Error, Info, Error, Info, Info]);
+    assert_eq!(lq.windows(5).count(), 3);
+    assert!(lq.windows(5).all(|w| w.len() == 5));
+    assert_eq!(lq.windows(1).count(), 7);
+    assert_eq!(lq.windows(8).count(), 0);
+
+    // detect 3 errors in 5 consecutive entries
+    let bursts = lq
+        .windows(5)
+        .filter(|w| w.iter().filter(|e| e.severity == Error).count() >= 3)
+        .count();
+    assert_eq!(bursts, 2);
+}
+
+#[test]
+fn chunks() {
+    let mut lq = queue(&vec![Severity::Info; 7]);
+    let sizes: Vec<usize> = lq.chunks(3).map(|c| c.len()).collect();
+    assert_eq!(sizes, vec![3, 3, 1]);
+    assert_eq!(lq.len(), 7);
+    assert_eq!(LogQueue::new().chunks(3).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn windows_zero() {
+    let _ = queue(&[Severity::Info]).windows(0).count();
+}
+
+#[test]
+fn windows_after_pop_front() {
+    // popping from the front and appending can leave the entries non-contiguous
+    let mut lq = LogQueue::new();
+    for n in 0..8u64 {
+        lq.log(LogEntry {
+            timestamp: n,
+            ..Default::default()
+        });
+    }
+    for n in 8..20u64 {
+        lq.pop_front();
+        lq.log(LogEntry {
+            timestamp: n,
+            ..Default::default()
+        });
+    }
+    let firsts: Vec<u64> = lq.windows(3).map(|w| w[0].timestamp).collect();
+    assert_eq!(firsts, (12..18).collect::<Vec<u64>>());
+    let chunks: Vec<Vec<u64>> = lq
+        .chunks(5)
+        .map(|c| c.iter().map(|e| e.timestamp).collect())
+        .collect();
+    assert_eq!(chunks, vec![vec![12, 13, 14, 15, 16], vec![17, 18, 19]]);
+    assert!(lq.is_sorted());
+    assert_eq!(lq.take().len(), 8);
+}
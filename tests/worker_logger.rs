@@ -0,0 +1,50 @@
+// WorkerLogger schedules sends with ctx.waitUntil
+//
+#![cfg(all(feature = "cloudflare-workers", target_arch = "wasm32"))]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+use service_logging::{CoralogixConfig, LogEntry, WorkerLogger};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+// a stand-in for the workers ExecutionContext, which counts calls to waitUntil
+fn mock_context() -> (js_sys::Object, worker::Context) {
+    let obj = js_sys::Object::new();
+    let wait_until = js_sys::Function::new_with_args("p", "this.calls = (this.calls || 0) + 1;");
+    js_sys::Reflect::set(&obj, &"waitUntil".into(), &wait_until).unwrap();
+    let ctx = worker::Context::new(obj.clone().unchecked_into());
+    (obj, ctx)
+}
+
+fn wait_until_calls(obj: &js_sys::Object) -> f64 {
+    js_sys::Reflect::get(obj, &"calls".into())
+        .unwrap()
+        .as_f64()
+        .unwrap_or(0.0)
+}
+
+#[wasm_bindgen_test]
+async fn worker_logger_wait_until() {
+    let (obj, ctx) = mock_context();
+    let logger = WorkerLogger::init(
+        &ctx,
+        CoralogixConfig {
+            api_key: "key",
+            application_name: "app",
+            endpoint: "http://127.0.0.1:9/api/v1/logs",
+            ..Default::default()
+        },
+    )
+    .expect("init");
+
+    // send returns without waiting for the request
+    logger
+        .send("sub", vec![LogEntry::default()])
+        .await
+        .expect("send");
+    assert_eq!(wait_until_calls(&obj), 1.0);
+
+    // empty batches aren't scheduled
+    logger.send("sub", Vec::new()).await.expect("send");
+    assert_eq!(wait_until_calls(&obj), 1.0);
+}